@@ -0,0 +1,79 @@
+// flips and the celebration are drawn from the frame timestamps seed passes to after_next_render,
+// so they move in step with the screen instead of on timers that drift on slow tablets
+use std::collections::BTreeMap;
+
+const FLIP_MS: f64 = 250.0;
+const CELEBRATION_FADE_MS: f64 = 600.0;
+
+#[derive(Default)]
+pub struct Animations {
+    // tile index -> frame its flip started on, None until the first frame after it was asked for
+    flips: BTreeMap<usize, Option<f64>>,
+    celebration: Option<Option<f64>>,
+    now: f64,
+    // a frame callback is already waiting, so don't ask for another
+    pub running: bool,
+}
+
+impl Animations {
+    pub fn flip(&mut self, index: usize) {
+        let _garbage = self.flips.insert(index, None);
+    }
+
+    pub fn celebrate(&mut self) {
+        if self.celebration.is_none() {
+            self.celebration = Some(None);
+        }
+    }
+
+    pub fn stop_celebrating(&mut self) {
+        self.celebration = None;
+    }
+
+    // a new board, nothing on the old one matters anymore
+    pub fn clear(&mut self) {
+        self.flips.clear();
+        self.celebration = None;
+    }
+
+    pub fn is_flipping(&self) -> bool {
+        !self.flips.is_empty()
+    }
+
+    // there's something that still needs frames
+    pub fn is_active(&self) -> bool {
+        self.is_flipping() || self.celebration_progress() < 1.0
+    }
+
+    // move everything on to this frame, returns whether there's anything left to draw
+    pub fn tick(&mut self, timestamp: f64) -> bool {
+        self.now = timestamp;
+        for start in self.flips.values_mut() {
+            start.get_or_insert(timestamp);
+        }
+        self.flips
+            .retain(|_, start| start.is_some_and(|start| timestamp - start < FLIP_MS));
+        if let Some(start) = &mut self.celebration {
+            start.get_or_insert(timestamp);
+        }
+
+        self.is_active()
+    }
+
+    // how far through its flip a tile is, from 0 to 1, or None if it's not flipping
+    pub fn flip_progress(&self, index: usize) -> Option<f64> {
+        let start = self.flips.get(&index)?;
+        Some(progress(*start, self.now, FLIP_MS))
+    }
+
+    pub fn celebration_progress(&self) -> f64 {
+        self.celebration
+            .map_or(1.0, |start| progress(start, self.now, CELEBRATION_FADE_MS))
+    }
+}
+
+// eased so things slow down as they settle
+fn progress(start: Option<f64>, now: f64, length: f64) -> f64 {
+    let linear = start.map_or(0.0, |start| ((now - start) / length).clamp(0.0, 1.0));
+    linear * (2.0 - linear)
+}
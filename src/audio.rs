@@ -0,0 +1,149 @@
+// sound clips on cards: audio files that were dropped or picked, or a recording from the
+// microphone. they're kept as data urls like the photos, and play when a tile turns over
+use seed::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use web_sys::{
+    Blob, BlobEvent, BlobPropertyBag, HtmlAudioElement, MediaRecorder, MediaStream,
+    MediaStreamConstraints, MediaStreamTrack,
+};
+
+// the kinds of sound file the drop zone turns into cards
+pub const AUDIO_TYPES: [&str; 5] = [
+    "audio/mpeg",
+    "audio/mp3",
+    "audio/ogg",
+    "audio/wav",
+    "audio/webm",
+];
+const AUDIO_EXTENSIONS: [&str; 4] = [".mp3", ".ogg", ".wav", ".webm"];
+
+// a few seconds of sound, anything longer fills up local storage fast
+const MAX_BYTES: usize = 1_000_000;
+
+pub fn is_audio_file(name: &str) -> bool {
+    let name = name.to_lowercase();
+    AUDIO_EXTENSIONS
+        .iter()
+        .any(|extension| name.ends_with(extension))
+}
+
+pub fn play(clip: &str) {
+    if let Ok(audio) = HtmlAudioElement::new_with_src(clip) {
+        // a browser that won't play it just stays quiet
+        let _garbage = audio.play();
+    }
+}
+
+fn data_url(bytes: &[u8], mime_type: &str) -> Result<String, String> {
+    if bytes.len() > MAX_BYTES {
+        return Err(format!(
+            "that sound is too long, it has to be under {} kB",
+            MAX_BYTES / 1000
+        ));
+    }
+    // some systems don't say what kind of file it is
+    let mime_type = if mime_type.starts_with("audio/") {
+        mime_type
+    } else {
+        "audio/mpeg"
+    };
+    Ok(format!(
+        "data:{};base64,{}",
+        mime_type,
+        base64::encode(bytes)
+    ))
+}
+
+async fn blob_data_url(blob: Blob, mime_type: String) -> Result<String, String> {
+    let buffer = seed::wasm_bindgen_futures::JsFuture::from(blob.array_buffer())
+        .await
+        .map_err(|_| "couldn't read the sound".to_string())?;
+    data_url(&js_sys::Uint8Array::new(&buffer).to_vec(), &mime_type)
+}
+
+// a picked or dropped sound file
+pub async fn from_file(file: web_sys::File) -> Result<String, String> {
+    let mime_type = file.type_();
+    blob_data_url(file.into(), mime_type).await
+}
+
+// a recording in progress
+pub struct Recorder {
+    recorder: MediaRecorder,
+    stream: MediaStream,
+    _on_data: Closure<dyn FnMut(BlobEvent)>,
+    _on_stop: Closure<dyn FnMut()>,
+}
+
+impl Recorder {
+    // ask for the microphone and start recording. `done` gets the clip after `stop`
+    pub async fn start(done: impl Fn(Result<String, String>) + 'static) -> Result<Self, String> {
+        let no_recording = |_| "this browser can't record sound".to_string();
+        let constraints = MediaStreamConstraints::new();
+        constraints.set_audio(&JsValue::TRUE);
+        let promise = seed::window()
+            .navigator()
+            .media_devices()
+            .map_err(no_recording)?
+            .get_user_media_with_constraints(&constraints)
+            .map_err(no_recording)?;
+        let stream: MediaStream = seed::wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(|_| "the microphone isn't available".to_string())?
+            .unchecked_into();
+        let recorder = MediaRecorder::new_with_media_stream(&stream).map_err(no_recording)?;
+
+        let chunks: Rc<RefCell<Vec<Blob>>> = Rc::default();
+        let on_data = {
+            let chunks = Rc::clone(&chunks);
+            Closure::wrap(Box::new(move |event: BlobEvent| {
+                if let Some(data) = event.data() {
+                    chunks.borrow_mut().push(data);
+                }
+            }) as Box<dyn FnMut(BlobEvent)>)
+        };
+
+        let mime_type = recorder.mime_type();
+        let done = Rc::new(done);
+        let on_stop = Closure::wrap(Box::new(move || {
+            let parts: js_sys::Array = chunks.borrow_mut().drain(..).collect();
+            let options = BlobPropertyBag::new();
+            options.set_type(&mime_type);
+            let blob = Blob::new_with_blob_sequence_and_options(&parts, &options);
+            let (done, mime_type) = (Rc::clone(&done), mime_type.clone());
+            seed::wasm_bindgen_futures::spawn_local(async move {
+                done(match blob {
+                    Ok(blob) => blob_data_url(blob, mime_type).await,
+                    Err(_) => Err("couldn't save the recording".to_string()),
+                });
+            });
+        }) as Box<dyn FnMut()>);
+
+        recorder.set_ondataavailable(Some(on_data.as_ref().unchecked_ref()));
+        recorder.set_onstop(Some(on_stop.as_ref().unchecked_ref()));
+        recorder.start().map_err(no_recording)?;
+
+        Ok(Self {
+            recorder,
+            stream,
+            _on_data: on_data,
+            _on_stop: on_stop,
+        })
+    }
+
+    pub fn stop(&self) {
+        let _garbage = self.recorder.stop();
+        // let go of the microphone so the browser's recording light goes off
+        for track in self.stream.get_tracks().iter() {
+            track.unchecked_into::<MediaStreamTrack>().stop();
+        }
+    }
+
+    // stop without keeping the clip. the handlers go first, they'd be gone by the time it stops
+    pub fn cancel(self) {
+        self.recorder.set_ondataavailable(None);
+        self.recorder.set_onstop(None);
+        self.stop();
+    }
+}
@@ -0,0 +1,99 @@
+// a picture for whoever is playing: one of the built in animals or their own photo, shown in
+// the corner of the board with their name
+use crate::{thumbnail, Msg};
+use seed::{prelude::*, *};
+
+pub const ICONS: [&str; 12] = [
+    "🐶", "🐱", "🦊", "🐼", "🐸", "🦁", "🐵", "🐧", "🦄", "🐙", "🐢", "🦖",
+];
+
+#[derive(Clone, PartialEq)]
+pub enum Avatar {
+    Icon(&'static str),
+    // a data url, made small by the same thumbnail code as card photos
+    Photo(String),
+}
+
+// read a picked file and shrink it like a card photo
+pub async fn from_file(file: web_sys::File) -> Result<Avatar, String> {
+    thumbnail::from_file(file).await.map(Avatar::Photo)
+}
+
+fn picture<Ms>(avatar: &Avatar, size: f64) -> Node<Ms> {
+    match avatar {
+        Avatar::Icon(icon) => span![style![St::FontSize => rem(size * 0.8)], icon],
+        Avatar::Photo(photo) => img![
+            attrs! {At::Src => photo, At::Alt => "avatar"},
+            style![
+                St::Width => rem(size),
+                St::Height => rem(size),
+                St::ObjectFit => "cover",
+                St::BorderRadius => percent(50),
+                St::VerticalAlign => "middle",
+            ],
+        ],
+    }
+}
+
+// the avatar and the player's name, for the top of the board
+pub fn badge<Ms>(avatar: Option<&Avatar>, name: &str) -> Node<Ms> {
+    if avatar.is_none() && name.trim().is_empty() {
+        return empty![];
+    }
+    p![
+        C!["title is-4"],
+        style![St::Display => "flex", St::AlignItems => "center", St::Gap => rem(0.5)],
+        avatar.map(|avatar| picture(avatar, 2.5)),
+        span![name.trim()],
+    ]
+}
+
+// the built in icons to pick from, plus uploading a photo
+pub fn picker(current: Option<&Avatar>, error: Option<&String>) -> Node<Msg> {
+    div![
+        C!["field"],
+        label![C!["label"], "avatar"],
+        div![
+            C!["buttons"],
+            ICONS.iter().map(|icon| {
+                let picked = current == Some(&Avatar::Icon(icon));
+                button![
+                    C!["button is-medium", IF!(picked => "is-link")],
+                    icon,
+                    ev(Ev::Click, move |_| Msg::SetAvatar(Some(Avatar::Icon(icon)))),
+                ]
+            }),
+            if let Some(Avatar::Photo(photo)) = current {
+                button![
+                    C!["button is-medium is-link"],
+                    picture(&Avatar::Photo(photo.clone()), 1.5),
+                ]
+            } else {
+                empty![]
+            },
+            label![
+                C!["button is-medium"],
+                "📷 Photo...",
+                input![
+                    attrs! {At::Type => "file", At::Accept => "image/*"},
+                    style![St::Display => "none"],
+                    ev(Ev::Change, |event| {
+                        let file = event
+                            .target()?
+                            .dyn_into::<web_sys::HtmlInputElement>()
+                            .ok()?
+                            .files()?
+                            .get(0)?;
+                        Some(Msg::AvatarFile(file))
+                    }),
+                ],
+            ],
+            IF!(current.is_some() => button![
+                C!["button is-medium"],
+                "None",
+                ev(Ev::Click, |_| Msg::SetAvatar(None)),
+            ]),
+        ],
+        error.map(|error| p![C!["help is-danger"], error]),
+    ]
+}
@@ -0,0 +1,107 @@
+// taking a photo for a new card with the device's camera, the back one on a tablet
+use crate::{thumbnail, Msg};
+use seed::{prelude::*, *};
+use web_sys::{
+    CanvasRenderingContext2d, HtmlCanvasElement, HtmlVideoElement, MediaStream,
+    MediaStreamConstraints, MediaStreamTrack,
+};
+
+pub struct Camera {
+    stream: MediaStream,
+    pub video: ElRef<HtmlVideoElement>,
+}
+
+impl Camera {
+    // ask for the camera. the picture shows up once `show` is called on the rendered page
+    pub async fn start() -> Result<Self, String> {
+        let no_camera = |_| "this browser can't use the camera".to_string();
+        // the camera facing away from the screen, when there's a choice
+        let video = js_sys::Object::new();
+        js_sys::Reflect::set(&video, &"facingMode".into(), &"environment".into())
+            .map_err(no_camera)?;
+        let constraints = MediaStreamConstraints::new();
+        constraints.set_video(&video);
+        let promise = seed::window()
+            .navigator()
+            .media_devices()
+            .map_err(no_camera)?
+            .get_user_media_with_constraints(&constraints)
+            .map_err(no_camera)?;
+        let stream = seed::wasm_bindgen_futures::JsFuture::from(promise)
+            .await
+            .map_err(|_| "the camera isn't available".to_string())?
+            .unchecked_into();
+        Ok(Self {
+            stream,
+            video: ElRef::default(),
+        })
+    }
+
+    pub fn show(&self) {
+        if let Some(video) = self.video.get() {
+            video.set_src_object(Some(&self.stream));
+            let _garbage = video.play();
+        }
+    }
+
+    // the current frame, made into a thumbnail like any other photo
+    pub fn capture(&self) -> Result<String, String> {
+        let failed = |_| "couldn't take the photo".to_string();
+        let video = self.video.get().ok_or("the camera isn't showing")?;
+        let canvas: HtmlCanvasElement = seed::document()
+            .create_element("canvas")
+            .map_err(failed)?
+            .unchecked_into();
+        canvas.set_width(video.video_width());
+        canvas.set_height(video.video_height());
+        let context: CanvasRenderingContext2d = canvas
+            .get_context("2d")
+            .map_err(failed)?
+            .ok_or("couldn't take the photo")?
+            .unchecked_into();
+        context
+            .draw_image_with_html_video_element(&video, 0., 0.)
+            .map_err(failed)?;
+        let frame = canvas.to_data_url_with_type("image/jpeg").map_err(failed)?;
+        let bytes = frame
+            .split_once(";base64,")
+            .and_then(|(_, b64)| base64::decode(b64).ok())
+            .ok_or("couldn't take the photo")?;
+        thumbnail::data_url(&bytes).map_err(|error| error.to_string())
+    }
+
+    pub fn stop(&self) {
+        // let go of the camera so its light goes off
+        for track in self.stream.get_tracks().iter() {
+            track.unchecked_into::<MediaStreamTrack>().stop();
+        }
+    }
+}
+
+pub fn view(camera: &Camera) -> Node<Msg> {
+    div![
+        C!["modal is-active"],
+        div![C!["modal-background"], ev(Ev::Click, |_| Msg::CloseCamera)],
+        div![
+            C!["modal-content box"],
+            video![
+                el_ref(&camera.video),
+                attrs! {At::from("playsinline") => AtValue::None, At::from("muted") => AtValue::None},
+                style![St::Width => percent(100)],
+            ],
+            div![
+                C!["buttons"],
+                button![
+                    "📸 Take Photo",
+                    C!["button is-large is-success"],
+                    ev(Ev::Click, |_| Msg::CapturePhoto),
+                ],
+                button![
+                    "Done",
+                    C!["button is-large"],
+                    ev(Ev::Click, |_| Msg::CloseCamera),
+                ],
+            ],
+        ],
+    ]
+}
@@ -0,0 +1,120 @@
+// challenge links: the cards from a finished game, the seed its board was shuffled with, and
+// how the challenger did, all packed into the link's #fragment. a friend who opens it gets the
+// exact same board, and sees how they compare at the end
+//
+// the fragment never goes to the server, so a deck with photos makes a long link but still
+// works
+use crate::{deck::Deck, game, share, Model, Msg};
+use seed::{prelude::*, *};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use ulid::Ulid;
+
+const HASH_PREFIX: &str = "challenge=";
+
+#[derive(Serialize, Deserialize)]
+pub struct Challenge {
+    pub deck: Deck,
+    pub seed: u64,
+    seconds: f64,
+    moves: u32,
+    player: String,
+}
+
+impl Challenge {
+    // the game that was just won, if its board came from a seed
+    pub fn from_model(model: &Model) -> Option<Self> {
+        let seed = model.board_seed?;
+        let seconds = model.finished_seconds?;
+        let dealt: BTreeSet<Ulid> = model.board.iter().map(|tile| tile.card.id).collect();
+        let mut deck = Deck::from_model(model);
+        deck.cards.retain(|card| dealt.contains(&card.id));
+        Some(Self {
+            deck,
+            seed,
+            seconds,
+            moves: game::moves(&model.tile_flips),
+            player: model.webhook.player.trim().to_string(),
+        })
+    }
+
+    pub fn link(&self) -> String {
+        let json = serde_json::to_string(self).expect("serialize challenge");
+        format!(
+            "{}#{}{}",
+            share::page_address(),
+            HASH_PREFIX,
+            base64::encode_config(json, base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    // the challenge in the url the app was opened with, if there is one
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let encoded = url.hash()?.strip_prefix(HASH_PREFIX)?;
+        let json = base64::decode_config(encoded, base64::URL_SAFE_NO_PAD).ok()?;
+        let challenge: Self = serde_json::from_slice(&json)
+            .map_err(|error| seed::log!("couldn't read the challenge:", error))
+            .ok()?;
+        challenge.deck.validate().ok()?;
+        Some(challenge)
+    }
+
+    fn challenger(&self) -> &str {
+        if self.player.is_empty() {
+            "your friend"
+        } else {
+            &self.player
+        }
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+// how the player did next to the challenger, once the board is cleared
+pub fn comparison(challenge: &Challenge, seconds: f64, moves: u32) -> Node<Msg> {
+    let verdict = match (seconds < challenge.seconds, moves <= challenge.moves) {
+        (true, true) => "🏆 You beat the challenge!",
+        (false, false) => "So close! Try again?",
+        _ => "🤝 Pretty even!",
+    };
+    div![
+        C!["box"],
+        style![St::TextAlign => "center"],
+        p![C!["title is-4"], verdict],
+        table![
+            C!["table"],
+            style![St::Margin => "0 auto"],
+            thead![tr![th![], th!["time"], th!["moves"]]],
+            tbody![
+                tr![
+                    td![strong!["you"]],
+                    td![format!("{:.1}s", seconds)],
+                    td![moves]
+                ],
+                tr![
+                    td![challenge.challenger()],
+                    td![format!("{:.1}s", challenge.seconds)],
+                    td![challenge.moves],
+                ],
+            ],
+        ],
+    ]
+}
+
+// the button that copies a challenge link, and how copying went
+pub fn share_button(status: Option<&Result<(), String>>) -> Node<Msg> {
+    div![
+        C!["block"],
+        button![
+            "Challenge a Friend",
+            C!["button is-large is-primary"],
+            attrs! {At::Title => "copy a link to this exact board, with your time and moves"},
+            ev(Ev::Click, |_| Msg::CopyChallenge),
+        ],
+        status.map(|status| match status {
+            Ok(()) => p![C!["help is-success"], "link copied, send it to a friend"],
+            Err(error) => p![C!["help is-danger"], format!("couldn't copy: {}", error)],
+        }),
+    ]
+}
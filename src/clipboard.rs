@@ -0,0 +1,40 @@
+// the async clipboard api (web-sys only has it behind the unstable apis flag)
+use seed::{prelude::*, JsFuture};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = ["navigator", "clipboard"], js_name = writeText, catch)]
+    fn write_text_promise(text: &str) -> Result<js_sys::Promise, JsValue>;
+
+    #[wasm_bindgen(js_namespace = ["navigator", "clipboard"], js_name = readText, catch)]
+    fn read_text_promise() -> Result<js_sys::Promise, JsValue>;
+}
+
+fn describe(error: &JsValue) -> String {
+    error
+        .as_string()
+        .or_else(|| {
+            error
+                .dyn_ref::<js_sys::Error>()
+                .map(|error| String::from(error.message()))
+        })
+        .unwrap_or_else(|| "the browser said no".to_string())
+}
+
+pub async fn write_text(text: String) -> Result<(), String> {
+    // navigator.clipboard is missing on plain http pages, which throws right away
+    let promise = write_text_promise(&text).map_err(|error| describe(&error))?;
+    JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(|error| describe(&error))
+}
+
+pub async fn read_text() -> Result<String, String> {
+    let promise = read_text_promise().map_err(|error| describe(&error))?;
+    JsFuture::from(promise)
+        .await
+        .map_err(|error| describe(&error))?
+        .as_string()
+        .ok_or_else(|| "there's no text on the clipboard".to_string())
+}
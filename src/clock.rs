@@ -0,0 +1,81 @@
+// a clock for every game, running from the deal, and the best time for each deck kept in the
+// browser. (the speedrun timer is the millisecond version, for competitive play)
+use crate::{storage, PlayedCard};
+use seed::{prelude::*, *};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const TICK_MS: u32 = 1000;
+
+#[derive(Default)]
+pub struct BestTimes {
+    boards: BTreeMap<String, f64>,
+}
+
+impl BestTimes {
+    pub fn load() -> Self {
+        Self {
+            boards: storage::load(storage::BEST_TIMES).unwrap_or_default(),
+        }
+    }
+
+    pub fn best(&self, board: &[PlayedCard]) -> Option<f64> {
+        self.boards.get(&board_key(board)).copied()
+    }
+
+    // a won game's time, true if it beat the best one
+    pub fn record(&mut self, board: &[PlayedCard], seconds: f64) -> bool {
+        let best = self.boards.entry(board_key(board)).or_insert(f64::INFINITY);
+        let new_best = seconds < *best;
+        if new_best {
+            *best = seconds;
+            storage::save(storage::BEST_TIMES, &self.boards);
+        }
+        new_best
+    }
+}
+
+// the cards on a board, so a few pairs from a deck don't compete with the whole deck, and a
+// deck keeps its times when it's renamed or isn't saved at all
+fn board_key(board: &[PlayedCard]) -> String {
+    let ids: BTreeSet<u128> = board.iter().map(|tile| u128::from(tile.card.id)).collect();
+    // fnv-1a, since std's hasher can change between builds
+    let hash = ids
+        .iter()
+        .flat_map(|id| id.to_be_bytes())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
+fn format(seconds: f64) -> String {
+    // never negative and far below u64::MAX
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let seconds = seconds.floor().max(0.0) as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+// the running time above the board
+pub fn hud<Ms>(seconds: f64, best: Option<f64>) -> Node<Ms> {
+    p![
+        C!["subtitle is-5"],
+        format!("⏱ {}", format(seconds)),
+        best.map(|best| span![C!["has-text-grey"], format!("  (best {})", format(best))]),
+    ]
+}
+
+// how long the won game took, against the best
+pub fn result<Ms>(seconds: f64, best: Option<f64>, new_best: bool) -> Node<Ms> {
+    div![
+        C!["box"],
+        p![C!["title is-4"], format!("⏱ {}", format(seconds))],
+        if new_best {
+            p![C!["subtitle is-5"], "🎉 a new best time for this deck!"]
+        } else {
+            best.map_or_else(
+                || empty![],
+                |best| p![C!["subtitle is-5"], format!("best time: {}", format(best))],
+            )
+        },
+    ]
+}
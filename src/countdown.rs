@@ -0,0 +1,105 @@
+// beat the clock mode: the board has to be cleared before the countdown runs out. running out
+// turns every card back over and the game is lost
+use crate::{CardState, Msg, PlayedCard};
+use seed::{prelude::*, *};
+
+const DEFAULT_SECONDS: u32 = 90;
+
+pub struct Countdown {
+    // js time (ms) the time runs out at
+    ends_at: f64,
+    expired: bool,
+    // pairs found before the time ran out, the board doesn't show them anymore
+    found: usize,
+    pairs: usize,
+}
+
+impl Countdown {
+    pub fn start(seconds: u32) -> Self {
+        Self {
+            ends_at: js_sys::Date::now() + f64::from(seconds) * 1000.0,
+            expired: false,
+            found: 0,
+            pairs: 0,
+        }
+    }
+
+    // time spent away from the game doesn't count
+    pub fn pause_for(&mut self, ms: f64) {
+        self.ends_at += ms;
+    }
+
+    pub fn is_due(&self, now: f64) -> bool {
+        !self.expired && now >= self.ends_at
+    }
+
+    // the game is lost, every tile goes back face down, found pairs too
+    pub fn run_out(&mut self, board: &mut [PlayedCard]) {
+        self.expired = true;
+        self.found = board.iter().filter(|tile| tile.matched).count() / 2;
+        self.pairs = board.len() / 2;
+        for tile in board {
+            tile.displayed = CardState::FaceDown;
+            tile.matched = false;
+        }
+    }
+
+    pub fn is_out(&self) -> bool {
+        self.expired
+    }
+
+    fn seconds_left(&self, now: f64) -> u64 {
+        // the clock runs in whole seconds, so this is never fractional in practice
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let seconds = ((self.ends_at - now) / 1000.0).ceil().max(0.0) as u64;
+        seconds
+    }
+}
+
+// parse the seconds setting, empty means the default
+pub fn seconds(setting: &str) -> u32 {
+    setting
+        .trim()
+        .parse()
+        .ok()
+        .filter(|seconds| *seconds > 0)
+        .unwrap_or(DEFAULT_SECONDS)
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn hud(countdown: &Countdown, now: f64) -> Node<Msg> {
+    let seconds = countdown.seconds_left(now);
+    p![
+        C!["title is-4", IF!(seconds <= 10 => "has-text-danger")],
+        format!("⏳ {}:{:02} left", seconds / 60, seconds % 60),
+    ]
+}
+
+// shown above the turned over board once time runs out
+pub fn time_up(countdown: &Countdown) -> Node<Msg> {
+    div![
+        C!["box"],
+        style![St::TextAlign => "center"],
+        h1![C!["title is-4"], "⏰ Out of time!"],
+        p![
+            C!["title is-5"],
+            format!("You found {} of {} pairs", countdown.found, countdown.pairs)
+        ],
+        div![
+            C!["buttons"],
+            style![St::JustifyContent => "center"],
+            button![
+                "Try Again",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::StartGame),
+            ],
+            button![
+                "Back to Editor",
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::EditDeck),
+            ],
+        ],
+    ]
+}
@@ -0,0 +1,96 @@
+// cards from a spreadsheet saved as csv: one card per row, and a second column (if there is
+// one) for the partner tile's text
+//
+// just enough csv for what spreadsheets write: commas or semicolons, quoted fields with "" for a
+// quote inside them, and \n or \r\n line endings
+
+// each row's first column, and its second one if it's there and not empty
+pub fn cards(bytes: &[u8]) -> Vec<(String, Option<String>)> {
+    let text = String::from_utf8_lossy(bytes);
+    // excel puts a byte order mark at the start
+    let text = text.trim_start_matches('\u{feff}');
+    let separator = if text
+        .lines()
+        .next()
+        .is_some_and(|first| first.matches(';').count() > first.matches(',').count())
+    {
+        ';'
+    } else {
+        ','
+    };
+
+    rows(text, separator)
+        .into_iter()
+        .filter_map(|row| {
+            let mut columns = row.into_iter().map(|column| column.trim().to_string());
+            let text = columns.next().filter(|text| !text.is_empty())?;
+            let text_b = columns.next().filter(|text_b| !text_b.is_empty());
+            Some((text, text_b))
+        })
+        .collect()
+}
+
+fn rows(text: &str, separator: char) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if quoted => field.push(c),
+            c if c == separator => row.push(std::mem::take(&mut field)),
+            '\r' => (),
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(text: &str, text_b: Option<&str>) -> (String, Option<String>) {
+        (text.to_string(), text_b.map(str::to_string))
+    }
+
+    #[test]
+    fn one_and_two_columns() {
+        let csv = "\u{feff}dog,perro\r\ncat\r\n\r\n bird , \n";
+        assert_eq!(
+            cards(csv.as_bytes()),
+            vec![
+                pair("dog", Some("perro")),
+                pair("cat", None),
+                pair("bird", None)
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_fields_and_semicolons() {
+        let csv = "\"salt; pepper\";\"sal y \"\"pimienta\"\"\"\n\"two\nlines\";dos";
+        assert_eq!(
+            cards(csv.as_bytes()),
+            vec![
+                pair("salt; pepper", Some("sal y \"pimienta\"")),
+                pair("two\nlines", Some("dos"))
+            ]
+        );
+    }
+}
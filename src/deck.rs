@@ -0,0 +1,98 @@
+// the deck as json, for files, links and anything else that moves a deck around
+use crate::{game::AnswerPolicy, speech::SpeechSettings, Card, Model};
+use serde::{Deserialize, Serialize};
+
+// bump this if the format ever changes in a way old versions can't read
+const VERSION: u32 = 1;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Deck {
+    pub version: u32,
+    #[serde(default)]
+    pub lang: Option<String>,
+    #[serde(default)]
+    pub voice: Option<String>,
+    #[serde(default)]
+    pub read_words: bool,
+    // how strictly typed or spoken answers are checked
+    #[serde(default)]
+    pub answer_policy: AnswerPolicy,
+    pub cards: Vec<Card>,
+}
+
+// a file name for the exported deck, from its saved name when it has one
+pub fn file_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() {
+        "deck.json".to_string()
+    } else {
+        format!("{}.json", name)
+    }
+}
+
+impl Deck {
+    pub fn from_model(model: &Model) -> Self {
+        Self {
+            version: VERSION,
+            lang: Some(model.speech.lang.clone()),
+            voice: model.speech.voice.clone(),
+            read_words: model.speech.read_words,
+            answer_policy: model.answer_policy,
+            cards: model.cards().cloned().collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("serialize deck")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let deck: Self =
+            serde_json::from_str(json).map_err(|error| format!("not a deck file: {}", error))?;
+        if deck.version > VERSION {
+            return Err("this deck was made with a newer version of the app".to_string());
+        }
+        Ok(deck)
+    }
+
+    // make sure an imported deck only has things the app can show safely
+    pub fn validate(&self) -> Result<(), String> {
+        if self.cards.is_empty() {
+            return Err("the deck has no cards".to_string());
+        }
+        for card in &self.cards {
+            // only pictures that are inside the deck, never links to other places
+            for photo in card.photo.iter().chain(&card.preview) {
+                if !photo.starts_with("data:image/") {
+                    return Err("a card has a picture that isn't an image".to_string());
+                }
+            }
+            // the same goes for recorded sounds
+            if let Some(audio) = &card.audio {
+                if !audio.starts_with("data:audio/") {
+                    return Err("a card has a sound that isn't audio".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn speech(&self) -> SpeechSettings {
+        let mut speech = SpeechSettings::default();
+        if let Some(lang) = &self.lang {
+            speech.lang = lang.clone();
+        }
+        speech.voice = self.voice.clone();
+        speech.read_words = self.read_words;
+        speech
+    }
+}
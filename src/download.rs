@@ -0,0 +1,28 @@
+// save generated files (decks, games) with the browser's normal download
+use seed::prelude::*;
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+// the download starts after the click has returned, the file's url has to last until then
+const REVOKE_AFTER_MS: u32 = 10_000;
+
+pub fn save_file(file_name: &str, mime_type: &str, contents: &str) {
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob =
+        Blob::new_with_str_sequence_and_options(&parts, &options).expect("make blob for download");
+    let url = Url::create_object_url_with_blob(&blob).expect("make url for download");
+
+    // a temporary link with the download attribute is the only way to name the file
+    let anchor: HtmlAnchorElement = seed::document()
+        .create_element("a")
+        .expect("create download link")
+        .unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    seed::wasm_bindgen_futures::spawn_local(cmds::timeout(REVOKE_AFTER_MS, move || {
+        let _garbage = Url::revoke_object_url(&url);
+    }));
+}
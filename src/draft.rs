@@ -0,0 +1,66 @@
+// the word list kept in the browser (IndexedDB), photos and all, so a refresh or a closed tab
+// doesn't lose a deck of hand entered cards
+use crate::{storage, Card, Model};
+use serde::{Deserialize, Serialize};
+
+// wait this long after an edit before saving, typing shouldn't write photos out every keystroke
+pub const SAVE_DELAY_MS: u32 = 1000;
+
+#[derive(Serialize, Deserialize)]
+pub struct Draft {
+    cards: Vec<Card>,
+}
+
+impl Draft {
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn into_cards(self) -> Vec<Card> {
+        self.cards
+    }
+}
+
+pub async fn load() -> Option<Draft> {
+    storage::load_large::<Draft>(storage::DRAFT)
+        .await
+        .filter(|draft| !draft.cards.is_empty())
+}
+
+// save the editor's word list, or forget the draft once the list has been cleared
+pub fn save(model: &Model) {
+    let cards: Vec<Card> = model.editor_cards().cloned().collect();
+    if cards.is_empty() {
+        clear();
+    } else {
+        storage::save_large(storage::DRAFT, &Draft { cards });
+    }
+}
+
+pub fn clear() {
+    storage::remove_large(storage::DRAFT);
+}
+
+// edits to cards that aren't the user's own list (a challenge's, or a game's being fixed mid
+// game) are kept apart, and offered back next time in case they were meant to be kept
+pub async fn load_unsaved() -> Option<Draft> {
+    storage::load_large::<Draft>(storage::UNSAVED_DRAFT)
+        .await
+        .filter(|draft| !draft.cards.is_empty())
+}
+
+pub fn save_unsaved(cards: Vec<Card>) {
+    if cards.is_empty() {
+        clear_unsaved();
+    } else {
+        storage::save_large(storage::UNSAVED_DRAFT, &Draft { cards });
+    }
+}
+
+pub fn clear_unsaved() {
+    storage::remove_large(storage::UNSAVED_DRAFT);
+}
@@ -0,0 +1,198 @@
+// working out what's being dragged onto the drop zone
+use crate::audio;
+use seed::prelude::*;
+use web_sys::{DataTransfer, FileList};
+
+// the kinds of picture the drop zone turns into cards
+const IMAGE_TYPES: [&str; 4] = ["image/png", "image/gif", "image/jpeg", "image/webp"];
+
+// what's being dragged, known before it's dropped so the drop zone can say what it'll take.
+// browsers only show the types during a drag, not names or contents
+#[derive(Default)]
+pub struct DragSummary {
+    pub images: usize,
+    pub sounds: usize,
+    // exported decks, whose cards get added
+    pub decks: usize,
+    pub unsupported: usize,
+    // highlighted text from another page or document
+    pub text: bool,
+    // a picture or link dragged from another web page
+    pub link: bool,
+}
+
+impl DragSummary {
+    pub fn from_transfer(transfer: &DataTransfer) -> Self {
+        let items = transfer.items();
+        let mut summary = Self::default();
+        for item in (0..items.length()).filter_map(|index| items.get(index)) {
+            if item.kind() != "file" {
+                summary.text |= item.type_() == "text/plain";
+                summary.link |= item.type_() == "text/uri-list";
+                continue;
+            }
+            if IMAGE_TYPES.contains(&item.type_().as_str()) {
+                summary.images += 1;
+            } else if audio::AUDIO_TYPES.contains(&item.type_().as_str()) {
+                summary.sounds += 1;
+            } else if item.type_() == "application/json" || item.type_() == "text/csv" {
+                summary.decks += 1;
+            } else {
+                summary.unsupported += 1;
+            }
+        }
+        summary
+    }
+
+    // like "3 images, 1 unsupported file"
+    pub fn describe(&self) -> Option<String> {
+        let mut parts = vec![];
+        if self.images > 0 {
+            parts.push(plural(self.images, "image"));
+        }
+        if self.sounds > 0 {
+            parts.push(plural(self.sounds, "sound"));
+        }
+        if self.decks > 0 {
+            parts.push(plural(self.decks, "deck"));
+        }
+        if self.unsupported > 0 {
+            parts.push(plural(self.unsupported, "unsupported file"));
+        }
+        // files and links come with text too, so only mention text when it's all there is
+        if parts.is_empty() && self.link {
+            parts.push("a picture from a web page".to_string());
+        } else if parts.is_empty() && self.text {
+            parts.push("text for new cards".to_string());
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+fn plural(count: usize, thing: &str) -> String {
+    if count == 1 {
+        format!("1 {}", thing)
+    } else {
+        format!("{} {}s", count, thing)
+    }
+}
+
+// a picked or dropped file's contents
+pub async fn read_bytes(file: web_sys::File) -> Result<Vec<u8>, String> {
+    seed::wasm_bindgen_futures::JsFuture::from(file.array_buffer())
+        .await
+        .map(|buffer| js_sys::Uint8Array::new(&buffer).to_vec())
+        .map_err(|_| format!("couldn't read {}", file.name()))
+}
+
+// a deck file's json, to go through the same checks as a pasted deck
+pub async fn read_text(file: web_sys::File) -> Result<String, String> {
+    seed::wasm_bindgen_futures::JsFuture::from(file.text())
+        .await
+        .ok()
+        .and_then(|text| text.as_string())
+        .ok_or_else(|| format!("couldn't read {}", file.name()))
+}
+
+// what was let go over the drop zone
+pub enum Dropped {
+    Files(FileList),
+    // a picture dragged out of another tab
+    ImageUrl(String),
+    Text(String),
+}
+
+impl Dropped {
+    pub fn from_transfer(transfer: &DataTransfer) -> Option<Self> {
+        if let Some(files) = transfer.files().filter(|files| files.length() > 0) {
+            return Some(Self::Files(files));
+        }
+        // dragging a picture gives its address as a link and as html, the <img> in the html is
+        // the picture itself even when it's wrapped in a link to somewhere else
+        let data = |format: &str| {
+            transfer
+                .get_data(format)
+                .ok()
+                .filter(|data| !data.is_empty())
+        };
+        if let Some(uri_list) = data("text/uri-list") {
+            let image = data("text/html").and_then(|html| image_source(&html));
+            let link = uri_list
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string);
+            if let Some(url) = image.or(link) {
+                return Some(Self::ImageUrl(url));
+            }
+        }
+        transfer
+            .get_data("text/plain")
+            .ok()
+            .filter(|text| !text.trim().is_empty())
+            .map(Self::Text)
+    }
+}
+
+// one card per line, or per comma or tab when it's all on one line, like a pasted list.
+// lines like "dog = perro", or two columns copied out of a spreadsheet, are a word and its
+// translation for the two tiles of a pair
+pub fn words(text: &str) -> Vec<(String, Option<String>)> {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let words: Vec<(&str, Option<&str>)> = match lines.as_slice() {
+        [line] if !line.contains(" = ") => line
+            .split([',', ';', '\t'])
+            .map(|word| (word, None))
+            .collect(),
+        _ => lines
+            .into_iter()
+            .map(
+                |line| match line.split_once('\t').or_else(|| line.split_once(" = ")) {
+                    Some((word, translation)) => (word, Some(translation.trim())),
+                    None => (line, None),
+                },
+            )
+            .collect(),
+    };
+
+    let mut seen = std::collections::BTreeSet::new();
+    words
+        .into_iter()
+        .map(|(word, translation)| (word.trim(), translation.filter(|text| !text.is_empty())))
+        .filter(|(word, _)| !word.is_empty() && seen.insert(word.to_lowercase()))
+        .map(|(word, translation)| (word.to_string(), translation.map(str::to_string)))
+        .collect()
+}
+
+// the src of the first <img> in some html, good enough for what browsers put in a drag
+fn image_source(html: &str) -> Option<String> {
+    let tag_start = html.to_lowercase().find("<img")?;
+    let tag = &html[tag_start..];
+    let tag = &tag[..tag.find('>')?];
+    let src_start = tag.to_lowercase().find("src=")? + "src=".len();
+    let src = &tag[src_start..];
+
+    let src = match src.chars().next()? {
+        quote @ ('"' | '\'') => src[1..].split(quote).next()?,
+        _ => src.split(char::is_whitespace).next()?,
+    };
+    // html escapes & in addresses
+    Some(src.replace("&amp;", "&")).filter(|src| !src.is_empty())
+}
+
+// download a dragged picture and make it into a card's thumbnail
+pub async fn fetch_image(url: String) -> Result<String, String> {
+    let bytes = fetch(url.as_str())
+        .await
+        .and_then(Response::check_status)
+        .map_err(|_| "couldn't download that picture, the site might not allow it. try saving it and dropping the file".to_string())?
+        .bytes()
+        .await
+        .map_err(|error| format!("download failed: {:?}", error))?;
+
+    crate::thumbnail::data_url(&bytes)
+        .map_err(|error| format!("that isn't a picture the app can use: {}", error))
+}
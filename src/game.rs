@@ -0,0 +1,472 @@
+// the rules for clicking tiles on the board. every click goes through `guess`, which ignores
+// clicks that don't make sense (a tile that's already showing, a stale index from a click that
+// raced a re-render, ...) so they can't mess up `last` and `needs_reset`
+use crate::{CardState, Model};
+use serde::{Deserialize, Serialize};
+
+// ways to play a deck
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Mode {
+    // the whole deck on one board
+    #[default]
+    Classic,
+    // a few pairs at first, more every round
+    Rounds,
+    // only so many wrong guesses
+    Lives,
+    // two players, two copies of the same board, first one done wins
+    Race,
+    // players taking turns on one board, a miss passes the turn
+    HotSeat,
+    // clear the board before the time runs out
+    Countdown,
+}
+
+impl Mode {
+    pub const ALL: [Self; 6] = [
+        Self::Classic,
+        Self::Rounds,
+        Self::Lives,
+        Self::Race,
+        Self::HotSeat,
+        Self::Countdown,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::Rounds => "Rounds (bigger board every round)",
+            Self::Lives => "Lives (limited wrong guesses)",
+            Self::Race => "Race (two players, side by side)",
+            Self::HotSeat => "Hot seat (2 to 6 players take turns)",
+            Self::Countdown => "Beat the clock (clear the board in time)",
+        }
+    }
+}
+
+// whether a wrong pair waits for a click to turn back over, or goes by itself after a moment
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum MissReset {
+    #[default]
+    Click,
+    Slow,
+    Quick,
+}
+
+impl MissReset {
+    pub const ALL: [Self; 3] = [Self::Click, Self::Slow, Self::Quick];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Click => "Click to turn them back",
+            Self::Slow => "By themselves after 3 seconds",
+            Self::Quick => "By themselves after 1.5 seconds",
+        }
+    }
+
+    pub const fn delay_ms(self) -> Option<u32> {
+        match self {
+            Self::Click => None,
+            Self::Slow => Some(3000),
+            Self::Quick => Some(1500),
+        }
+    }
+}
+
+// how long a single flipped tile stays up before it turns itself back over
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Difficulty {
+    #[default]
+    Relaxed,
+    Normal,
+    Hard,
+    Expert,
+}
+
+impl Difficulty {
+    pub const ALL: [Self; 4] = [Self::Relaxed, Self::Normal, Self::Hard, Self::Expert];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Relaxed => "Relaxed (cards stay up)",
+            Self::Normal => "Normal (5 seconds)",
+            Self::Hard => "Hard (3 seconds)",
+            Self::Expert => "Expert (1.5 seconds)",
+        }
+    }
+
+    pub const fn flip_back_ms(self) -> Option<u32> {
+        match self {
+            Self::Relaxed => None,
+            Self::Normal => Some(5000),
+            Self::Hard => Some(3000),
+            Self::Expert => Some(1500),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    // the click didn't do anything
+    Ignored,
+    // two wrong tiles were showing, so the click just turned them back over
+    Reset,
+    // first tile of a guess
+    Flipped,
+    Matched,
+    Missed,
+}
+
+impl Outcome {
+    // a tile was turned face up by this click
+    pub fn flipped_tile(&self) -> bool {
+        matches!(self, Self::Flipped | Self::Matched | Self::Missed)
+    }
+}
+
+pub fn guess(model: &mut Model, index: usize) -> Outcome {
+    let Some(tile) = model.board.get(index) else {
+        return Outcome::Ignored;
+    };
+
+    // after a wrong guess any click turns the two tiles back over
+    if model.needs_reset {
+        model.all_face_down();
+        return Outcome::Reset;
+    }
+
+    // a tile that's already showing can't be picked again, that's how clicking the same tile
+    // twice used to count as a match
+    if tile.matched || tile.displayed == CardState::FaceUp {
+        return Outcome::Ignored;
+    }
+
+    let just_guessed = tile.card.id;
+    match model.last {
+        // this will be the only flipped card, so remember it and show it
+        None => {
+            model.last = Some(just_guessed);
+            model.board[index].displayed = CardState::FaceUp;
+            model.flips += 1;
+            Outcome::Flipped
+        }
+        // the person guessed correctly!
+        Some(last_guessed) if last_guessed == just_guessed => {
+            for card in &mut model.board {
+                if card.card.id == just_guessed {
+                    card.matched = true;
+                }
+            }
+            // matched tiles show regardless, so the rest can go face down
+            model.all_face_down();
+            Outcome::Matched
+        }
+        // guessed incorrectly :(
+        Some(_) => {
+            model.board[index].displayed = CardState::FaceUp;
+            model.needs_reset = true;
+            Outcome::Missed
+        }
+    }
+}
+
+// how close a typed or spoken answer (like spelling practice's) has to be to the card's text to
+// count. each one forgives everything the ones before it do
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnswerPolicy {
+    Exact,
+    #[default]
+    IgnoreCase,
+    IgnoreAccents,
+    OneTypo,
+}
+
+// letters with accents and what they are without them
+const ACCENTS: [(&str, char); 14] = [
+    ("àáâãäåā", 'a'),
+    ("çćč", 'c'),
+    ("ď", 'd'),
+    ("èéêëēěę", 'e'),
+    ("ìíîïī", 'i'),
+    ("ł", 'l'),
+    ("ñńň", 'n'),
+    ("òóôõöøō", 'o'),
+    ("ř", 'r'),
+    ("śšß", 's'),
+    ("ť", 't'),
+    ("ùúûüūů", 'u'),
+    ("ýÿ", 'y'),
+    ("źżž", 'z'),
+];
+
+impl AnswerPolicy {
+    pub const ALL: [Self; 4] = [
+        Self::Exact,
+        Self::IgnoreCase,
+        Self::IgnoreAccents,
+        Self::OneTypo,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Exact => "Exactly right",
+            Self::IgnoreCase => "Capitals don't matter",
+            Self::IgnoreAccents => "Capitals and accents don't matter",
+            Self::OneTypo => "One typo is fine too",
+        }
+    }
+
+    pub fn accepts(self, answer: &str, expected: &str) -> bool {
+        let (answer, expected) = (answer.trim(), expected.trim());
+        match self {
+            Self::Exact => answer == expected,
+            Self::IgnoreCase => answer.to_lowercase() == expected.to_lowercase(),
+            Self::IgnoreAccents => fold(answer) == fold(expected),
+            Self::OneTypo => {
+                let (answer, expected) = (fold(answer), fold(expected));
+                // short words would match almost anything with a typo allowed
+                answer == expected || (expected.len() > 3 && edit_distance(&answer, &expected) <= 1)
+            }
+        }
+    }
+}
+
+// lowercase and without accents
+fn fold(text: &str) -> Vec<char> {
+    text.to_lowercase()
+        .chars()
+        .map(|letter| {
+            ACCENTS
+                .iter()
+                .find(|(accented, _)| accented.contains(letter))
+                .map_or(letter, |(_, plain)| *plain)
+        })
+        .collect()
+}
+
+// how many letters have to be added, removed or changed to get from one to the other
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_letter) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_letter) in b.iter().enumerate() {
+            let changed = previous[j] + usize::from(a_letter != b_letter);
+            current.push(changed.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// two tiles turned over make one move
+pub fn moves(tile_flips: &[u32]) -> u32 {
+    tile_flips.iter().sum::<u32>().div_ceil(2)
+}
+
+// one to three stars for how few moves a board took. with a good memory a board takes about
+// twice as many moves as it has pairs
+pub fn stars(moves: u32, pairs: usize) -> u8 {
+    let moves = moves as usize;
+    if moves <= pairs.saturating_mul(2) {
+        3
+    } else if moves <= pairs.saturating_mul(3) {
+        2
+    } else {
+        1
+    }
+}
+
+// the player took too long to pick a second tile. `flip` is the count from when the timer was
+// set, so a timer left over from an earlier guess doesn't turn over a newer tile
+pub fn flip_back(model: &mut Model, flip: u32) -> bool {
+    if flip != model.flips || model.last.is_none() || model.needs_reset {
+        return false;
+    }
+    model.all_face_down();
+    true
+}
+
+// a wrong pair's delay is up. `flip` works like it does for `flip_back`
+pub fn miss_flip_back(model: &mut Model, flip: u32) -> bool {
+    if flip != model.flips || !model.needs_reset {
+        return false;
+    }
+    model.all_face_down();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Card, PairHalf, PlayedCard};
+    use ulid::Ulid;
+
+    // a board with the tiles of two cards, laid out a a b b
+    fn two_pairs() -> Model {
+        let tiles = |text: &str| {
+            let card = Card {
+                text: Some(text.to_string()),
+                text_b: None,
+                sound: None,
+                audio: None,
+                photo: None,
+                preview: None,
+                id: Ulid::new(),
+            };
+            [PairHalf::A, PairHalf::A].map(|half| PlayedCard {
+                card: card.clone(),
+                half,
+                displayed: CardState::FaceDown,
+                matched: false,
+            })
+        };
+
+        let mut model = Model {
+            game_started: true,
+            ..Model::default()
+        };
+        model.board.extend(tiles("cat"));
+        model.board.extend(tiles("dog"));
+        model
+    }
+
+    fn face_up(model: &Model) -> Vec<usize> {
+        (0..model.board.len())
+            .filter(|index| model.board[*index].displayed == CardState::FaceUp)
+            .collect()
+    }
+
+    #[test]
+    fn matching_tiles_are_matched() {
+        let mut model = two_pairs();
+        assert_eq!(guess(&mut model, 0), Outcome::Flipped);
+        assert_eq!(guess(&mut model, 1), Outcome::Matched);
+        assert!(model.board[0].matched && model.board[1].matched);
+        assert!(!model.board[2].matched && !model.board[3].matched);
+        assert_eq!(model.last, None);
+    }
+
+    #[test]
+    fn same_tile_twice_is_not_a_match() {
+        let mut model = two_pairs();
+        assert_eq!(guess(&mut model, 0), Outcome::Flipped);
+        assert_eq!(guess(&mut model, 0), Outcome::Ignored);
+        assert!(!model.board[0].matched);
+        assert_eq!(model.last, Some(model.board[0].card.id));
+        assert_eq!(face_up(&model), vec![0]);
+    }
+
+    #[test]
+    fn wrong_guess_waits_for_a_reset() {
+        let mut model = two_pairs();
+        guess(&mut model, 0);
+        assert_eq!(guess(&mut model, 2), Outcome::Missed);
+        assert!(model.needs_reset);
+        assert_eq!(face_up(&model), vec![0, 2]);
+
+        // the next click only turns them back over, even on a face down tile
+        assert_eq!(guess(&mut model, 3), Outcome::Reset);
+        assert!(!model.needs_reset);
+        assert_eq!(model.last, None);
+        assert!(face_up(&model).is_empty());
+    }
+
+    #[test]
+    fn wrong_guess_flips_back_after_its_delay() {
+        let mut model = two_pairs();
+        guess(&mut model, 0);
+        guess(&mut model, 2);
+        let flip = model.flips;
+
+        // a timer from before this guess doesn't count
+        assert!(!miss_flip_back(&mut model, flip - 1));
+        assert_eq!(face_up(&model), vec![0, 2]);
+
+        assert!(miss_flip_back(&mut model, flip));
+        assert!(!model.needs_reset);
+        assert!(face_up(&model).is_empty());
+        // and once they're down there's nothing left for it to do
+        assert!(!miss_flip_back(&mut model, flip));
+    }
+
+    #[test]
+    fn matched_tiles_are_ignored() {
+        let mut model = two_pairs();
+        guess(&mut model, 0);
+        guess(&mut model, 1);
+        assert_eq!(guess(&mut model, 0), Outcome::Ignored);
+        assert_eq!(model.last, None);
+
+        // and don't get in the way of the next guess
+        assert_eq!(guess(&mut model, 2), Outcome::Flipped);
+        assert_eq!(guess(&mut model, 1), Outcome::Ignored);
+        assert_eq!(guess(&mut model, 3), Outcome::Matched);
+    }
+
+    #[test]
+    fn out_of_range_is_ignored() {
+        let mut model = two_pairs();
+        assert_eq!(guess(&mut model, 4), Outcome::Ignored);
+        assert_eq!(guess(&mut model, usize::MAX), Outcome::Ignored);
+        assert_eq!(model.last, None);
+
+        let mut empty = Model::default();
+        assert_eq!(guess(&mut empty, 0), Outcome::Ignored);
+    }
+
+    #[test]
+    fn tiles_showing_after_show_all_are_ignored() {
+        let mut model = two_pairs();
+        for tile in &mut model.board {
+            tile.displayed = CardState::FaceUp;
+        }
+        assert_eq!(guess(&mut model, 1), Outcome::Ignored);
+        assert_eq!(model.last, None);
+    }
+
+    #[test]
+    fn answer_policies() {
+        assert!(AnswerPolicy::Exact.accepts(" Café ", "Café"));
+        assert!(!AnswerPolicy::Exact.accepts("café", "Café"));
+
+        assert!(AnswerPolicy::IgnoreCase.accepts("CAFÉ", "café"));
+        assert!(!AnswerPolicy::IgnoreCase.accepts("cafe", "café"));
+
+        assert!(AnswerPolicy::IgnoreAccents.accepts("Cafe", "café"));
+        assert!(!AnswerPolicy::IgnoreAccents.accepts("cafes", "café"));
+
+        assert!(!AnswerPolicy::OneTypo.accepts("elefant", "Elephant"));
+        assert!(AnswerPolicy::OneTypo.accepts("elephnt", "Elephant"));
+        assert!(AnswerPolicy::OneTypo.accepts("elephamt", "éléphant"));
+        // too short to guess at
+        assert!(!AnswerPolicy::OneTypo.accepts("cot", "cat"));
+    }
+
+    #[test]
+    fn fewer_moves_more_stars() {
+        assert_eq!(stars(5, 5), 3);
+        assert_eq!(stars(10, 5), 3);
+        assert_eq!(stars(11, 5), 2);
+        assert_eq!(stars(15, 5), 2);
+        assert_eq!(stars(16, 5), 1);
+    }
+
+    #[test]
+    fn stale_flip_back_is_ignored() {
+        let mut model = two_pairs();
+        assert_eq!(guess(&mut model, 0), Outcome::Flipped);
+        let old = model.flips;
+        assert_eq!(guess(&mut model, 1), Outcome::Matched);
+        assert_eq!(guess(&mut model, 2), Outcome::Flipped);
+
+        // the first tile's timer can't turn over the new one
+        assert!(!flip_back(&mut model, old));
+        assert_eq!(face_up(&model), vec![2]);
+
+        let current = model.flips;
+        assert!(flip_back(&mut model, current));
+        assert!(face_up(&model).is_empty());
+        assert_eq!(model.last, None);
+    }
+}
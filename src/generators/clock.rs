@@ -0,0 +1,172 @@
+// analog clock faces matched with the time written digitally
+use crate::NewCardType;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use seed::{prelude::*, *};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Granularity {
+    Hours,
+    HalfHours,
+    FiveMinutes,
+}
+
+impl Granularity {
+    const ALL: [Self; 3] = [Self::Hours, Self::HalfHours, Self::FiveMinutes];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Hours => "o'clock",
+            Self::HalfHours => "half hours",
+            Self::FiveMinutes => "five minutes",
+        }
+    }
+
+    const fn step_minutes(self) -> u32 {
+        match self {
+            Self::Hours => 60,
+            Self::HalfHours => 30,
+            Self::FiveMinutes => 5,
+        }
+    }
+}
+
+pub struct Model {
+    granularity: Granularity,
+    pairs: usize,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self {
+            granularity: Granularity::Hours,
+            pairs: 8,
+        }
+    }
+}
+
+pub enum Msg {
+    SetGranularity(Granularity),
+    SetPairs(String),
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::SetGranularity(granularity) => model.granularity = granularity,
+        Msg::SetPairs(pairs) => {
+            if let Ok(pairs) = pairs.parse() {
+                model.pairs = pairs;
+            }
+        }
+    }
+}
+
+// pick different times on a 12 hour clock and draw each one
+pub fn generate(model: &Model) -> Vec<NewCardType> {
+    let step = model.granularity.step_minutes();
+    let mut times: Vec<(u32, u32)> = (0..12 * 60)
+        .step_by(step as usize)
+        .map(|minutes| {
+            let hour = minutes / 60;
+            (if hour == 0 { 12 } else { hour }, minutes % 60)
+        })
+        .collect();
+    times.shuffle(&mut thread_rng());
+    times.truncate(model.pairs);
+
+    times
+        .into_iter()
+        .map(|(hour, minute)| {
+            let face = format!(
+                "data:image/svg+xml;base64,{}",
+                base64::encode(clock_svg(hour, minute))
+            );
+            NewCardType::PhotoPair(face, format!("{}:{:02}", hour, minute))
+        })
+        .collect()
+}
+
+// a point `length` out from the middle of the clock at `degrees` (0 is 12 o'clock)
+fn point(degrees: f64, length: f64) -> (f64, f64) {
+    let radians = degrees.to_radians();
+    (
+        100.0 + length * radians.sin(),
+        100.0 - length * radians.cos(),
+    )
+}
+
+fn hand(degrees: f64, length: f64, width: u32) -> String {
+    let (x, y) = point(degrees, length);
+    format!(
+        r#"<line x1="100" y1="100" x2="{:.1}" y2="{:.1}" stroke="black" stroke-width="{}" stroke-linecap="round"/>"#,
+        x, y, width
+    )
+}
+
+fn clock_svg(hour: u32, minute: u32) -> String {
+    let numbers: String = (1..=12)
+        .map(|number| {
+            let (x, y) = point(f64::from(number) * 30.0, 72.0);
+            format!(
+                r#"<text x="{:.1}" y="{:.1}" font-size="20" font-family="sans-serif" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                x, y, number
+            )
+        })
+        .collect();
+    let ticks: String = (0..60)
+        .map(|tick| {
+            let inner = if tick % 5 == 0 { 84.0 } else { 89.0 };
+            let (x1, y1) = point(f64::from(tick) * 6.0, inner);
+            let (x2, y2) = point(f64::from(tick) * 6.0, 93.0);
+            format!(
+                r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="black" stroke-width="{}"/>"#,
+                x1,
+                y1,
+                x2,
+                y2,
+                if tick % 5 == 0 { 3 } else { 1 }
+            )
+        })
+        .collect();
+
+    // the hour hand moves along as the minutes go by, like a real clock
+    let hour_degrees = (f64::from(hour % 12) + f64::from(minute) / 60.0) * 30.0;
+    let minute_degrees = f64::from(minute) * 6.0;
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 200 200"><rect width="200" height="200" fill="white"/><circle cx="100" cy="100" r="95" fill="white" stroke="black" stroke-width="4"/>{}{}{}{}<circle cx="100" cy="100" r="5" fill="black"/></svg>"#,
+        ticks,
+        numbers,
+        hand(hour_degrees, 45.0, 7),
+        hand(minute_degrees, 70.0, 4),
+    )
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["buttons"],
+            Granularity::ALL.iter().map(|granularity| {
+                let granularity = *granularity;
+                button![
+                    granularity.name(),
+                    C!["button", IF!(granularity == model.granularity => "is-link")],
+                    ev(Ev::Click, move |_| Msg::SetGranularity(granularity)),
+                ]
+            }),
+        ],
+        div![
+            C!["field"],
+            label![C!["label is-small"], "pairs"],
+            input![
+                C!["input is-small"],
+                attrs! {
+                    At::Type => "number",
+                    At::Min => "1",
+                    At::Value => model.pairs,
+                },
+                input_ev(Ev::Change, Msg::SetPairs),
+            ],
+        ],
+    ]
+}
@@ -0,0 +1,152 @@
+// a solid color swatch matched with the color's name
+use crate::NewCardType;
+use seed::{prelude::*, *};
+use std::collections::BTreeSet;
+
+// languages the color names come in, the order matches the names in COLORS
+const LANGUAGES: [&str; 6] = [
+    "English",
+    "Español",
+    "Français",
+    "Deutsch",
+    "Português",
+    "Italiano",
+];
+
+// (swatch color, names in each language)
+const COLORS: [(&str, [&str; 6]); 11] = [
+    (
+        "#e53935",
+        ["red", "rojo", "rouge", "rot", "vermelho", "rosso"],
+    ),
+    (
+        "#fb8c00",
+        [
+            "orange",
+            "naranja",
+            "orange",
+            "orange",
+            "laranja",
+            "arancione",
+        ],
+    ),
+    (
+        "#fdd835",
+        ["yellow", "amarillo", "jaune", "gelb", "amarelo", "giallo"],
+    ),
+    (
+        "#43a047",
+        ["green", "verde", "vert", "grün", "verde", "verde"],
+    ),
+    ("#1e88e5", ["blue", "azul", "bleu", "blau", "azul", "blu"]),
+    (
+        "#8e24aa",
+        ["purple", "morado", "violet", "lila", "roxo", "viola"],
+    ),
+    ("#f48fb1", ["pink", "rosa", "rose", "rosa", "rosa", "rosa"]),
+    (
+        "#6d4c41",
+        ["brown", "marrón", "marron", "braun", "marrom", "marrone"],
+    ),
+    (
+        "#000000",
+        ["black", "negro", "noir", "schwarz", "preto", "nero"],
+    ),
+    (
+        "#ffffff",
+        ["white", "blanco", "blanc", "weiß", "branco", "bianco"],
+    ),
+    (
+        "#9e9e9e",
+        ["gray", "gris", "gris", "grau", "cinza", "grigio"],
+    ),
+];
+
+#[derive(Default)]
+pub struct Model {
+    // index into LANGUAGES
+    language: usize,
+    // indexes into COLORS that are turned off
+    skipped: BTreeSet<usize>,
+}
+
+pub enum Msg {
+    SetLanguage(String),
+    ToggleColor(usize),
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::SetLanguage(language) => {
+            if let Some(index) = LANGUAGES.iter().position(|name| *name == language) {
+                model.language = index;
+            }
+        }
+        Msg::ToggleColor(index) => {
+            if !model.skipped.remove(&index) {
+                model.skipped.insert(index);
+            }
+        }
+    }
+}
+
+fn swatch(hex: &str) -> String {
+    // the thin border keeps white visible on a white card
+    let svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100"><rect x="2" y="2" width="96" height="96" rx="8" fill="{}" stroke="#bdbdbd" stroke-width="2"/></svg>"##,
+        hex
+    );
+    format!("data:image/svg+xml;base64,{}", base64::encode(svg))
+}
+
+pub fn generate(model: &Model) -> Vec<NewCardType> {
+    COLORS
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !model.skipped.contains(index))
+        .map(|(_, (hex, names))| {
+            NewCardType::PhotoPair(swatch(hex), names[model.language].to_string())
+        })
+        .collect()
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["field"],
+            div![
+                C!["select is-small"],
+                select![
+                    LANGUAGES.iter().enumerate().map(|(index, name)| option![
+                        attrs! {
+                            At::Value => name,
+                            At::Selected => (index == model.language).as_at_value(),
+                        },
+                        name
+                    ]),
+                    input_ev(Ev::Change, Msg::SetLanguage),
+                ],
+            ],
+        ],
+        div![
+            C!["buttons"],
+            COLORS.iter().enumerate().map(|(index, (hex, names))| {
+                let on = !model.skipped.contains(&index);
+                button![
+                    C!["button is-small", IF!(!on => "is-light")],
+                    span![style![
+                        St::Display => "inline-block",
+                        St::Width => em(1),
+                        St::Height => em(1),
+                        St::MarginRight => em(0.4),
+                        St::Background => hex,
+                        St::Border => "1px solid #bdbdbd",
+                        St::Opacity => if on { "1" } else { "0.3" },
+                    ],],
+                    names[model.language],
+                    ev(Ev::Click, move |_| Msg::ToggleColor(index)),
+                ]
+            }),
+        ],
+    ]
+}
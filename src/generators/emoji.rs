@@ -0,0 +1,96 @@
+// instant decks made from emoji, for games with no preparation at all
+use crate::NewCardType;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{thread_rng, Rng};
+
+const PAIRS: usize = 8;
+
+const ANIMALS: [(&str, &str); 16] = [
+    ("🐶", "dog"),
+    ("🐱", "cat"),
+    ("🐭", "mouse"),
+    ("🐰", "rabbit"),
+    ("🦊", "fox"),
+    ("🐻", "bear"),
+    ("🐼", "panda"),
+    ("🐨", "koala"),
+    ("🐯", "tiger"),
+    ("🦁", "lion"),
+    ("🐮", "cow"),
+    ("🐷", "pig"),
+    ("🐸", "frog"),
+    ("🐵", "monkey"),
+    ("🐔", "chicken"),
+    ("🐧", "penguin"),
+];
+
+const FOOD: [(&str, &str); 16] = [
+    ("🍎", "apple"),
+    ("🍌", "banana"),
+    ("🍇", "grapes"),
+    ("🍓", "strawberry"),
+    ("🍉", "watermelon"),
+    ("🍒", "cherries"),
+    ("🥕", "carrot"),
+    ("🌽", "corn"),
+    ("🍕", "pizza"),
+    ("🍔", "hamburger"),
+    ("🍞", "bread"),
+    ("🧀", "cheese"),
+    ("🥚", "egg"),
+    ("🍪", "cookie"),
+    ("🍦", "ice cream"),
+    ("🥛", "milk"),
+];
+
+const VEHICLES: [(&str, &str); 16] = [
+    ("🚗", "car"),
+    ("🚕", "taxi"),
+    ("🚌", "bus"),
+    ("🚒", "fire truck"),
+    ("🚓", "police car"),
+    ("🚑", "ambulance"),
+    ("🚲", "bicycle"),
+    ("🏍️", "motorcycle"),
+    ("🚂", "train"),
+    ("✈️", "airplane"),
+    ("🚁", "helicopter"),
+    ("🚀", "rocket"),
+    ("⛵", "sailboat"),
+    ("🚜", "tractor"),
+    ("🛴", "scooter"),
+    ("🚚", "truck"),
+];
+
+const CATEGORIES: [&[(&str, &str)]; 3] = [&ANIMALS, &FOOD, &VEHICLES];
+
+// draw the emoji big so it fills the picture part of a card
+fn emoji_picture(emoji: &str) -> String {
+    let svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100"><text x="50" y="54" font-size="72" text-anchor="middle" dominant-baseline="central">{}</text></svg>"#,
+        emoji
+    );
+    format!("data:image/svg+xml;base64,{}", base64::encode(svg))
+}
+
+// a random mix of one or more categories
+pub fn surprise() -> Vec<NewCardType> {
+    let mut rng = thread_rng();
+
+    let how_many_categories = rng.gen_range(1..=CATEGORIES.len());
+    let categories = CATEGORIES
+        .iter()
+        .choose_multiple(&mut rng, how_many_categories);
+
+    let mut emoji: Vec<&(&str, &str)> = categories
+        .iter()
+        .flat_map(|category| category.iter())
+        .collect();
+    emoji.shuffle(&mut rng);
+
+    emoji
+        .into_iter()
+        .take(PAIRS)
+        .map(|(emoji, name)| NewCardType::PhotoWithText(emoji_picture(emoji), (*name).to_string()))
+        .collect()
+}
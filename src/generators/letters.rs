@@ -0,0 +1,76 @@
+// pairs of big and small letters (A and a), a standard early literacy game
+use crate::NewCardType;
+use seed::{prelude::*, *};
+
+const ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const VOWELS: &str = "AEIOU";
+
+pub struct Model {
+    // which letters to make pairs for
+    letters: String,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self {
+            letters: ALPHABET.to_string(),
+        }
+    }
+}
+
+pub enum Msg {
+    SetLetters(String),
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::SetLetters(letters) => model.letters = letters,
+    }
+}
+
+// one pair per letter, skipping repeats and anything that isn't a letter
+pub fn generate(model: &Model) -> Vec<NewCardType> {
+    let mut seen = vec![];
+    model
+        .letters
+        .chars()
+        .filter(|letter| letter.is_alphabetic())
+        .filter_map(|letter| {
+            let big: String = letter.to_uppercase().collect();
+            let small: String = letter.to_lowercase().collect();
+            // letters without a big/small form would make identical tiles
+            if big == small || seen.contains(&big) {
+                return None;
+            }
+            seen.push(big.clone());
+            Some(NewCardType::TextPair(big, small))
+        })
+        .collect()
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["field"],
+            label![C!["label is-small"], "letters"],
+            input![
+                C!["input"],
+                attrs! {At::Value => model.letters},
+                input_ev(Ev::Input, Msg::SetLetters),
+            ],
+        ],
+        div![
+            C!["buttons"],
+            button![
+                "A–Z",
+                C!["button is-small"],
+                ev(Ev::Click, |_| Msg::SetLetters(ALPHABET.to_string())),
+            ],
+            button![
+                "vowels",
+                C!["button is-small"],
+                ev(Ev::Click, |_| Msg::SetLetters(VOWELS.to_string())),
+            ],
+        ],
+    ]
+}
@@ -0,0 +1,168 @@
+// arithmetic pairs like "3 × 4" and "12"
+use crate::NewCardType;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+use seed::{prelude::*, *};
+use std::collections::BTreeSet;
+
+// give up looking for new problems after this many tries (small ranges run out fast)
+const MAX_TRIES: usize = 1000;
+// keeps products small enough to fit on a card (and in a u32)
+const BIGGEST_NUMBER: u32 = 1000;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Operation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    pub const ALL: [Self; 4] = [Self::Add, Self::Subtract, Self::Multiply, Self::Divide];
+
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Subtract => "−",
+            Self::Multiply => "×",
+            Self::Divide => "÷",
+        }
+    }
+}
+
+pub struct Model {
+    pub operations: BTreeSet<Operation>,
+    // smallest and biggest numbers used in the problems
+    pub min: u32,
+    pub max: u32,
+    pub pairs: usize,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self {
+            operations: [Operation::Add].iter().copied().collect(),
+            min: 1,
+            max: 10,
+            pairs: 8,
+        }
+    }
+}
+
+// (problem, answer)
+fn problem(operation: Operation, a: u32, b: u32) -> (String, u32) {
+    match operation {
+        Operation::Add => (format!("{} + {}", a, b), a + b),
+        // keep answers positive by putting the bigger number first
+        Operation::Subtract => (format!("{} − {}", a.max(b), a.min(b)), a.max(b) - a.min(b)),
+        Operation::Multiply => (format!("{} × {}", a, b), a * b),
+        // build division from a multiplication so it always comes out even
+        Operation::Divide => (format!("{} ÷ {}", a * b.max(1), b.max(1)), a),
+    }
+}
+
+// make up to `settings.pairs` problems, every one with a different answer
+// (two tiles both saying "12" but only matching their own problem would be confusing)
+pub fn generate(settings: &Model) -> Vec<NewCardType> {
+    let operations: Vec<Operation> = settings.operations.iter().copied().collect();
+    if operations.is_empty() {
+        return vec![];
+    }
+    let (min, max) = (
+        settings.min.min(settings.max).min(BIGGEST_NUMBER),
+        settings.min.max(settings.max).min(BIGGEST_NUMBER),
+    );
+
+    let mut rng = thread_rng();
+    let mut answers = BTreeSet::new();
+    let mut pairs = vec![];
+    for _ in 0..MAX_TRIES {
+        if pairs.len() >= settings.pairs {
+            break;
+        }
+        let operation = *operations.choose(&mut rng).expect("operations isn't empty");
+        let (text, answer) = problem(
+            operation,
+            rng.gen_range(min..=max),
+            rng.gen_range(min..=max),
+        );
+        if answers.insert(answer) {
+            pairs.push(NewCardType::TextPair(text, answer.to_string()));
+        }
+    }
+    pairs
+}
+
+pub enum Msg {
+    ToggleOperation(Operation),
+    SetMin(String),
+    SetMax(String),
+    SetPairs(String),
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::ToggleOperation(operation) => {
+            if !model.operations.remove(&operation) {
+                model.operations.insert(operation);
+            }
+        }
+        Msg::SetMin(min) => {
+            if let Ok(min) = min.parse() {
+                model.min = min;
+            }
+        }
+        Msg::SetMax(max) => {
+            if let Ok(max) = max.parse() {
+                model.max = max;
+            }
+        }
+        Msg::SetPairs(pairs) => {
+            if let Ok(pairs) = pairs.parse() {
+                model.pairs = pairs;
+            }
+        }
+    }
+}
+
+pub fn view(settings: &Model) -> Node<Msg> {
+    let number_field = |name: &str, value: String, msg: fn(String) -> Msg| {
+        div![
+            C!["control"],
+            label![C!["label is-small"], name],
+            input![
+                C!["input is-small"],
+                attrs! {
+                    At::Type => "number",
+                    At::Min => "0",
+                    At::Value => value,
+                },
+                input_ev(Ev::Change, msg),
+            ],
+        ]
+    };
+
+    div![
+        div![
+            C!["buttons"],
+            Operation::ALL.iter().map(|operation| {
+                let operation = *operation;
+                button![
+                    operation.symbol(),
+                    C![
+                        "button",
+                        IF!(settings.operations.contains(&operation) => "is-link")
+                    ],
+                    ev(Ev::Click, move |_| Msg::ToggleOperation(operation)),
+                ]
+            }),
+        ],
+        div![
+            C!["field is-grouped"],
+            number_field("smallest number", settings.min.to_string(), Msg::SetMin),
+            number_field("biggest number", settings.max.to_string(), Msg::SetMax),
+            number_field("pairs", settings.pairs.to_string(), Msg::SetPairs),
+        ],
+    ]
+}
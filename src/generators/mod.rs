@@ -0,0 +1,154 @@
+// built in deck generators, for decks that can be made without typing or uploading anything
+//
+// every generator keeps its own options and turns them into NewCardTypes, so the app adds
+// generated cards exactly like ones the teacher made by hand
+use crate::NewCardType;
+use seed::{prelude::*, *};
+
+mod clock;
+mod colors;
+mod emoji;
+mod letters;
+mod math;
+mod phonics;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Math,
+    Letters,
+    Clock,
+    Colors,
+    Phonics,
+}
+
+impl Kind {
+    const ALL: [Self; 5] = [
+        Self::Math,
+        Self::Letters,
+        Self::Clock,
+        Self::Colors,
+        Self::Phonics,
+    ];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Math => "Math pairs",
+            Self::Letters => "Big & small letters",
+            Self::Clock => "Telling time",
+            Self::Colors => "Colors",
+            Self::Phonics => "Phonics",
+        }
+    }
+}
+
+pub use emoji::surprise;
+
+// ------ ------
+//     Model
+// ------ ------
+#[derive(Default)]
+pub struct Model {
+    // the generator panel that's showing, if any
+    open: Option<Kind>,
+    math: math::Model,
+    letters: letters::Model,
+    clock: clock::Model,
+    colors: colors::Model,
+    phonics: phonics::Model,
+}
+
+// ------ ------
+//    Update
+// ------ ------
+pub enum Msg {
+    Toggle,
+    Open(Kind),
+    Math(math::Msg),
+    Letters(letters::Msg),
+    Clock(clock::Msg),
+    Colors(colors::Msg),
+    Phonics(phonics::Msg),
+    // handled by the app, since it owns the deck
+    Generate,
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Toggle => {
+            model.open = match model.open {
+                Some(_) => None,
+                None => Some(Kind::ALL[0]),
+            }
+        }
+        Msg::Open(kind) => model.open = Some(kind),
+        Msg::Math(msg) => math::update(msg, &mut model.math),
+        Msg::Letters(msg) => letters::update(msg, &mut model.letters),
+        Msg::Clock(msg) => clock::update(msg, &mut model.clock),
+        Msg::Colors(msg) => colors::update(msg, &mut model.colors),
+        Msg::Phonics(msg) => phonics::update(msg, &mut model.phonics),
+        Msg::Generate => (),
+    }
+}
+
+// open a generator with its usual options (or close the panel), for the deck templates
+pub fn start(model: &mut Model, kind: Option<Kind>) {
+    match kind {
+        Some(Kind::Math) => model.math = math::Model::default(),
+        Some(Kind::Letters) => model.letters = letters::Model::default(),
+        Some(Kind::Clock) => model.clock = clock::Model::default(),
+        Some(Kind::Colors) => model.colors = colors::Model::default(),
+        Some(Kind::Phonics) => model.phonics = phonics::Model::default(),
+        None => (),
+    }
+    model.open = kind;
+}
+
+// the cards the open generator would make right now
+pub fn generate(model: &Model) -> Vec<NewCardType> {
+    match model.open {
+        Some(Kind::Math) => math::generate(&model.math),
+        Some(Kind::Letters) => letters::generate(&model.letters),
+        Some(Kind::Clock) => clock::generate(&model.clock),
+        Some(Kind::Colors) => colors::generate(&model.colors),
+        Some(Kind::Phonics) => phonics::generate(&model.phonics),
+        None => vec![],
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn view(model: &Model) -> Node<Msg> {
+    let open = match model.open {
+        Some(open) => open,
+        None => return empty![],
+    };
+
+    let options = match open {
+        Kind::Math => math::view(&model.math).map_msg(Msg::Math),
+        Kind::Letters => letters::view(&model.letters).map_msg(Msg::Letters),
+        Kind::Clock => clock::view(&model.clock).map_msg(Msg::Clock),
+        Kind::Colors => colors::view(&model.colors).map_msg(Msg::Colors),
+        Kind::Phonics => phonics::view(&model.phonics).map_msg(Msg::Phonics),
+    };
+
+    div![
+        C!["box"],
+        div![
+            C!["tabs"],
+            ul![Kind::ALL.iter().map(|kind| {
+                let kind = *kind;
+                li![
+                    C![IF!(kind == open => "is-active")],
+                    a![kind.name(), ev(Ev::Click, move |_| Msg::Open(kind))],
+                ]
+            })],
+        ],
+        options,
+        button![
+            "Add these cards",
+            C!["button is-success"],
+            ev(Ev::Click, |_| Msg::Generate),
+        ],
+    ]
+}
@@ -0,0 +1,85 @@
+// letter combinations ("sh", "ch", "th") matched with a card that says a word with that sound
+use crate::NewCardType;
+use seed::{prelude::*, *};
+use std::collections::BTreeSet;
+
+// (grapheme, key word read out loud for it)
+const GRAPHEMES: [(&str, &str); 16] = [
+    ("sh", "ship"),
+    ("ch", "chip"),
+    ("th", "thumb"),
+    ("wh", "whale"),
+    ("ph", "phone"),
+    ("ck", "duck"),
+    ("ng", "ring"),
+    ("qu", "queen"),
+    ("ee", "tree"),
+    ("oo", "moon"),
+    ("ai", "rain"),
+    ("oa", "boat"),
+    ("ar", "car"),
+    ("or", "fork"),
+    ("igh", "night"),
+    ("ow", "cow"),
+];
+
+#[derive(Default)]
+pub struct Model {
+    // indexes into GRAPHEMES that are turned off
+    skipped: BTreeSet<usize>,
+}
+
+pub enum Msg {
+    Toggle(usize),
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Toggle(index) => {
+            if !model.skipped.remove(&index) {
+                model.skipped.insert(index);
+            }
+        }
+    }
+}
+
+pub fn generate(model: &Model) -> Vec<NewCardType> {
+    GRAPHEMES
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !model.skipped.contains(index))
+        .map(|(_, (grapheme, word))| {
+            NewCardType::SoundPair((*grapheme).to_string(), (*word).to_string())
+        })
+        .collect()
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        IF!(!crate::speech::is_supported() => p![
+            C!["help is-danger"],
+            "this browser can't speak, so the sound cards will be silent"
+        ]),
+        p![
+            C!["help"],
+            "each letter card matches a card that says a word with that sound"
+        ],
+        div![
+            C!["buttons"],
+            GRAPHEMES
+                .iter()
+                .enumerate()
+                .map(|(index, (grapheme, word))| {
+                    button![
+                        C![
+                            "button is-small",
+                            IF!(!model.skipped.contains(&index) => "is-link")
+                        ],
+                        attrs! {At::Title => word},
+                        grapheme,
+                        ev(Ev::Click, move |_| Msg::Toggle(index)),
+                    ]
+                }),
+        ],
+    ]
+}
@@ -0,0 +1,73 @@
+// an opt in leaderboard on a user configured server, for a friendly competition between
+// classes
+//
+// won games are POSTed to the url as json ({"deck": ..., "name": ..., "moves": ..., "seconds":
+// ..., "date": ...}), and the rankings come from a GET to the same url with ?deck=<name>,
+// answered with a json list of entries in the same shape (or {"entries": [...]})
+use crate::leaderboard::Entry;
+use seed::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GlobalLeaderboardSettings {
+    pub url: String,
+}
+
+impl GlobalLeaderboardSettings {
+    pub fn is_configured(&self) -> bool {
+        !self.url.trim().is_empty()
+    }
+}
+
+#[derive(Serialize)]
+struct Submission<'a> {
+    deck: &'a str,
+    #[serde(flatten)]
+    entry: &'a Entry,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Rankings {
+    List(Vec<Entry>),
+    Wrapped { entries: Vec<Entry> },
+}
+
+// send a won game, then get the rankings for its deck back
+pub async fn submit(
+    settings: GlobalLeaderboardSettings,
+    deck: String,
+    entry: Entry,
+) -> Result<Vec<Entry>, String> {
+    let url = settings.url.trim().to_string();
+    Request::new(url.clone())
+        .method(Method::Post)
+        .json(&Submission {
+            deck: &deck,
+            entry: &entry,
+        })
+        .map_err(|error| format!("couldn't make request: {:?}", error))?
+        .fetch()
+        .await
+        .and_then(Response::check_status)
+        .map_err(|error| format!("couldn't send the result: {:?}", error))?;
+
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let rankings: Rankings = fetch(format!(
+        "{}{}deck={}",
+        url,
+        separator,
+        String::from(js_sys::encode_uri_component(&deck))
+    ))
+    .await
+    .and_then(Response::check_status)
+    .map_err(|error| format!("couldn't get the rankings: {:?}", error))?
+    .json()
+    .await
+    .map_err(|error| format!("unexpected rankings from the server: {:?}", error))?;
+
+    Ok(match rankings {
+        Rankings::List(entries) | Rankings::Wrapped { entries } => entries,
+    })
+}
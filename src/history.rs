@@ -0,0 +1,108 @@
+// undo and redo for the editor. every command is the list of cards it changed, with how each
+// card looked before and after, so undoing just puts the old cards back
+use crate::Card;
+use std::collections::BTreeMap;
+use ulid::Ulid;
+
+// plenty for a deck, without keeping every photo forever
+const LIMIT: usize = 100;
+
+struct Change {
+    id: Ulid,
+    // None when the card didn't exist on that side
+    before: Option<Card>,
+    after: Option<Card>,
+}
+
+type Command = Vec<Change>;
+
+#[derive(Default)]
+pub struct History {
+    undo: Vec<Command>,
+    redo: Vec<Command>,
+    // the card that's being typed in, so a word is one undo and not one per letter
+    typing_in: Option<Ulid>,
+}
+
+impl History {
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    // remember whatever changed between the two lists as one command
+    pub fn record(
+        &mut self,
+        before: &BTreeMap<Ulid, Card>,
+        after: &BTreeMap<Ulid, Card>,
+        typing: bool,
+    ) {
+        let changes: Command = before
+            .keys()
+            .chain(after.keys().filter(|id| !before.contains_key(id)))
+            .filter(|id| before.get(id) != after.get(id))
+            .map(|id| Change {
+                id: *id,
+                before: before.get(id).cloned(),
+                after: after.get(id).cloned(),
+            })
+            .collect();
+        if changes.is_empty() {
+            return;
+        }
+        self.redo.clear();
+
+        let typed_card = match changes.as_slice() {
+            [change] if typing => Some(change.id),
+            _ => None,
+        };
+
+        // still typing in the same card, so just move the end of the last command
+        if typed_card.is_some() && typed_card == self.typing_in {
+            if let Some([last]) = self.undo.last_mut().map(Vec::as_mut_slice) {
+                last.after = changes.into_iter().next().and_then(|change| change.after);
+                return;
+            }
+        }
+
+        self.typing_in = typed_card;
+        self.undo.push(changes);
+        if self.undo.len() > LIMIT {
+            let _garbage = self.undo.remove(0);
+        }
+    }
+
+    pub fn undo(&mut self, words_list: &mut BTreeMap<Ulid, Card>) {
+        if let Some(command) = self.undo.pop() {
+            for change in command.iter().rev() {
+                put(words_list, change.id, change.before.clone());
+            }
+            self.redo.push(command);
+        }
+        self.typing_in = None;
+    }
+
+    pub fn redo(&mut self, words_list: &mut BTreeMap<Ulid, Card>) {
+        if let Some(command) = self.redo.pop() {
+            for change in &command {
+                put(words_list, change.id, change.after.clone());
+            }
+            self.undo.push(command);
+        }
+        self.typing_in = None;
+    }
+}
+
+fn put(words_list: &mut BTreeMap<Ulid, Card>, id: Ulid, card: Option<Card>) {
+    match card {
+        Some(card) => {
+            let _garbage = words_list.insert(id, card);
+        }
+        None => {
+            let _garbage = words_list.remove(&id);
+        }
+    }
+}
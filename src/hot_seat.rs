@@ -0,0 +1,175 @@
+// hot seat mode: two to six players (or classroom teams) take turns on the same board and the
+// same device. finding a pair scores it and earns another go, a miss passes the turn on
+use crate::{game, Msg};
+use seed::{prelude::*, *};
+
+pub const MIN_PLAYERS: usize = 2;
+pub const MAX_PLAYERS: usize = 6;
+
+pub struct Player {
+    pub name: String,
+    pub pairs: u32,
+}
+
+pub struct HotSeat {
+    pub players: Vec<Player>,
+    // whose go it is
+    pub turn: usize,
+}
+
+impl HotSeat {
+    // one player for each name, empty names are just numbered
+    pub fn new(names: &[String]) -> Self {
+        let players = (0..names.len().clamp(MIN_PLAYERS, MAX_PLAYERS))
+            .map(|player| Player {
+                name: names
+                    .get(player)
+                    .map(|name| name.trim())
+                    .filter(|name| !name.is_empty())
+                    .map_or_else(|| player_name(player), str::to_string),
+                pairs: 0,
+            })
+            .collect();
+        Self { players, turn: 0 }
+    }
+
+    pub fn record(&mut self, outcome: &game::Outcome) {
+        match outcome {
+            game::Outcome::Matched => self.players[self.turn].pairs += 1,
+            game::Outcome::Missed => self.turn = (self.turn + 1) % self.players.len(),
+            _ => (),
+        }
+    }
+
+    // everyone on the top score, more than one is a tie
+    pub fn winners(&self) -> Vec<&Player> {
+        let top = self.players.iter().map(|player| player.pairs).max();
+        self.players
+            .iter()
+            .filter(|player| Some(player.pairs) == top)
+            .collect()
+    }
+}
+
+pub fn player_name(player: usize) -> String {
+    format!("Player {}", player + 1)
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// before the deal: who's playing, in the order they take turns
+pub fn setup_view(names: &[String]) -> Node<Msg> {
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "Who's playing?"],
+        p![
+            C!["subtitle is-6"],
+            "players take turns in this order, finding a pair earns another go"
+        ],
+        names.iter().enumerate().map(|(player, name)| {
+            div![
+                C!["field has-addons"],
+                div![
+                    C!["control"],
+                    input![
+                        C!["input is-medium"],
+                        attrs! {
+                            At::Value => name,
+                            At::Placeholder => player_name(player),
+                            At::AriaLabel => player_name(player),
+                        },
+                        input_ev(Ev::Input, move |name| Msg::SetHotSeatName(player, name)),
+                    ],
+                ],
+                IF!(names.len() > MIN_PLAYERS => div![
+                    C!["control"],
+                    button![
+                        "✕",
+                        C!["button is-medium"],
+                        attrs! {At::Title => "remove this player"},
+                        ev(Ev::Click, move |_| Msg::RemoveHotSeatPlayer(player)),
+                    ],
+                ]),
+            ]
+        }),
+        div![
+            C!["buttons"],
+            IF!(names.len() < MAX_PLAYERS => button![
+                "+ Add Player",
+                C!["button is-medium"],
+                ev(Ev::Click, |_| Msg::AddHotSeatPlayer),
+            ]),
+        ],
+        div![
+            C!["buttons"],
+            button![
+                "Start!",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::StartGame),
+            ],
+            button![
+                "Back",
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::CancelPlayerSetup),
+            ],
+        ],
+    ]
+}
+
+// the board with everyone's pairs down the side, the player whose turn it is picked out
+pub fn with_scoreboard(hot_seat: &HotSeat, board: Node<Msg>) -> Node<Msg> {
+    div![
+        C!["columns"],
+        div![C!["column"], board],
+        div![
+            C!["column is-narrow"],
+            aside![
+                C!["panel"],
+                p![C!["panel-heading"], "Scores"],
+                hot_seat.players.iter().enumerate().map(|(index, player)| {
+                    let turn = index == hot_seat.turn;
+                    div![
+                        C!["panel-block", IF!(turn => "is-active has-text-weight-bold")],
+                        IF!(turn => attrs! {At::from("aria-current") => "true"}),
+                        span![style![St::MinWidth => em(1.5)], if turn { "▶" } else { "" }],
+                        span![C!["is-size-5"], &player.name],
+                        span![
+                            C!["tag is-medium", IF!(turn => "is-primary")],
+                            style![St::MarginLeft => "auto"],
+                            player.pairs,
+                        ],
+                    ]
+                }),
+            ],
+        ],
+    ]
+}
+
+pub fn result(hot_seat: &HotSeat) -> Node<Msg> {
+    let winners = hot_seat.winners();
+    let headline = match winners.as_slice() {
+        [winner] => format!("🏆 {} wins!", winner.name),
+        _ => "🤝 It's a tie!".to_string(),
+    };
+    // highest score first, ties stay in turn order
+    let mut ranked: Vec<&Player> = hot_seat.players.iter().collect();
+    ranked.sort_by_key(|player| std::cmp::Reverse(player.pairs));
+    div![
+        C!["box"],
+        style![St::TextAlign => "center"],
+        p![C!["title is-2"], headline],
+        ranked.iter().map(|player| {
+            p![
+                C!["subtitle is-5"],
+                format!(
+                    "{}: {} {}",
+                    player.name,
+                    player.pairs,
+                    if player.pairs == 1 { "pair" } else { "pairs" }
+                )
+            ]
+        }),
+    ]
+}
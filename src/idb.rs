@@ -0,0 +1,87 @@
+// a little key/value store in IndexedDB, for what's too big for localStorage's few megabytes:
+// decks and word lists full of photos. values are json strings, like in localStorage
+use seed::{prelude::*, wasm_bindgen_futures::JsFuture};
+use std::cell::RefCell;
+use web_sys::{IdbDatabase, IdbObjectStore, IdbOpenDbRequest, IdbRequest, IdbTransactionMode};
+
+const DATABASE: &str = "matching-seed";
+const STORE: &str = "values";
+const VERSION: u32 = 1;
+
+thread_local! {
+    // one connection for the whole time the page is open, opened the first time it's needed
+    static CONNECTION: RefCell<Option<IdbDatabase>> = const { RefCell::new(None) };
+}
+
+// wait for a request to finish, and get what it found
+async fn finished(request: &IdbRequest) -> Result<JsValue, JsValue> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise).await?;
+    request.result()
+}
+
+async fn open() -> Result<IdbDatabase, JsValue> {
+    if let Some(database) = CONNECTION.with(|connection| connection.borrow().clone()) {
+        return Ok(database);
+    }
+    let request: IdbOpenDbRequest = seed::window()
+        .indexed_db()?
+        .ok_or_else(|| JsValue::from_str("this browser has no IndexedDB"))?
+        .open_with_u32(DATABASE, VERSION)?;
+
+    // the very first time, the store has to be made
+    let upgrading = request.clone();
+    let on_upgrade = Closure::once(move |_: JsValue| {
+        if let Ok(database) = upgrading.result() {
+            let _garbage = database
+                .unchecked_into::<IdbDatabase>()
+                .create_object_store(STORE);
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+
+    let database = finished(&request).await;
+    request.set_onupgradeneeded(None);
+    let database: IdbDatabase = database?.unchecked_into();
+    // (another call might have opened one while this one waited, only one is kept)
+    Ok(CONNECTION.with(|connection| {
+        let mut connection = connection.borrow_mut();
+        if let Some(opened) = &*connection {
+            database.close();
+            return opened.clone();
+        }
+        *connection = Some(database.clone());
+        database
+    }))
+}
+
+async fn store(mode: IdbTransactionMode) -> Result<IdbObjectStore, JsValue> {
+    open()
+        .await?
+        .transaction_with_str_and_mode(STORE, mode)?
+        .object_store(STORE)
+}
+
+pub async fn get(key: &str) -> Result<Option<String>, JsValue> {
+    let request = store(IdbTransactionMode::Readonly)
+        .await?
+        .get(&JsValue::from_str(key))?;
+    Ok(finished(&request).await?.as_string())
+}
+
+pub async fn put(key: &str, value: &str) -> Result<(), JsValue> {
+    let request = store(IdbTransactionMode::Readwrite)
+        .await?
+        .put_with_key(&JsValue::from_str(value), &JsValue::from_str(key))?;
+    finished(&request).await.map(|_| ())
+}
+
+pub async fn delete(key: &str) -> Result<(), JsValue> {
+    let request = store(IdbTransactionMode::Readwrite)
+        .await?
+        .delete(&JsValue::from_str(key))?;
+    finished(&request).await.map(|_| ())
+}
@@ -0,0 +1,111 @@
+// ask a user configured image generation api to draw a picture for a card
+//
+// the endpoint gets a POST with an openai style body ({"prompt": ..., "n": 1, ...}) and a bearer
+// token, and can answer with either raw image bytes or openai style json
+// ({"data": [{"b64_json": ...}]} or {"data": [{"url": ...}]})
+use seed::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ImageGenSettings {
+    pub endpoint: String,
+    pub api_key: String,
+}
+
+impl ImageGenSettings {
+    pub fn is_configured(&self) -> bool {
+        !self.endpoint.trim().is_empty()
+    }
+}
+
+#[derive(Serialize)]
+struct GenerateRequest {
+    prompt: String,
+    n: u32,
+    size: &'static str,
+    response_format: &'static str,
+}
+
+#[derive(Deserialize)]
+struct GenerateResponse {
+    data: Vec<GeneratedImage>,
+}
+
+#[derive(Deserialize)]
+struct GeneratedImage {
+    b64_json: Option<String>,
+    url: Option<String>,
+}
+
+fn prompt_for(text: &str) -> String {
+    format!(
+        "a simple, colorful illustration of \"{}\" on a plain background, for a children's flashcard",
+        text
+    )
+}
+
+// generate a picture for `text` and return it as a thumbnail data url
+pub async fn generate(settings: ImageGenSettings, text: String) -> Result<String, String> {
+    let body = GenerateRequest {
+        prompt: prompt_for(&text),
+        n: 1,
+        size: "256x256",
+        response_format: "b64_json",
+    };
+
+    let mut request = Request::new(settings.endpoint.trim().to_string())
+        .method(Method::Post)
+        .json(&body)
+        .map_err(|error| format!("couldn't make request: {:?}", error))?;
+    if !settings.api_key.trim().is_empty() {
+        request = request.header(Header::bearer(settings.api_key.trim().to_string()));
+    }
+
+    let response = request
+        .fetch()
+        .await
+        .and_then(Response::check_status)
+        .map_err(|error| format!("generating failed: {:?}", error))?;
+
+    let is_image = response
+        .raw_response()
+        .headers()
+        .get("content-type")
+        .ok()
+        .flatten()
+        .is_some_and(|content_type| content_type.starts_with("image/"));
+
+    let bytes = if is_image {
+        response
+            .bytes()
+            .await
+            .map_err(|error| format!("couldn't read picture: {:?}", error))?
+    } else {
+        let generated: GenerateResponse = response
+            .json()
+            .await
+            .map_err(|error| format!("unexpected answer from the endpoint: {:?}", error))?;
+        let image = generated
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| "the endpoint didn't send a picture".to_string())?;
+
+        match (image.b64_json, image.url) {
+            (Some(b64), _) => {
+                base64::decode(b64).map_err(|error| format!("bad picture data: {}", error))?
+            }
+            (None, Some(url)) => fetch(url)
+                .await
+                .and_then(Response::check_status)
+                .map_err(|error| format!("download failed: {:?}", error))?
+                .bytes()
+                .await
+                .map_err(|error| format!("download failed: {:?}", error))?,
+            (None, None) => return Err("the endpoint didn't send a picture".to_string()),
+        }
+    };
+
+    crate::thumbnail::data_url(&bytes).map_err(|error| format!("couldn't read picture: {}", error))
+}
@@ -0,0 +1,105 @@
+// dropped pictures wait their turn here and only a few become thumbnails at once, so a pile of
+// thirty big photos doesn't start decoding all together, and the drop zone can say how far along
+// the pile is
+use crate::Msg;
+use seed::{prelude::*, *};
+use std::collections::VecDeque;
+
+// thumbnails being made at the same time
+const AT_ONCE: usize = 3;
+
+#[derive(Default)]
+pub struct ImageQueue {
+    waiting: VecDeque<web_sys::File>,
+    running: usize,
+    done: usize,
+    // every picture in this batch, done or not
+    total: usize,
+    // the names of files that couldn't be made into cards, and why, until they're dismissed
+    failed: Vec<(String, String)>,
+}
+
+impl ImageQueue {
+    pub fn add(&mut self, file: web_sys::File) {
+        // a new batch counts from zero again
+        if self.is_idle() {
+            self.done = 0;
+            self.total = 0;
+        }
+        self.waiting.push_back(file);
+        self.total += 1;
+    }
+
+    // the pictures to start on now, as many as there's room for
+    pub fn start(&mut self) -> Vec<web_sys::File> {
+        let room = AT_ONCE.saturating_sub(self.running).min(self.waiting.len());
+        self.running += room;
+        self.waiting.drain(..room).collect()
+    }
+
+    // one picture is done, whether or not it worked
+    pub fn finished(&mut self, name: String, result: &Result<String, String>) {
+        self.running = self.running.saturating_sub(1);
+        self.done += 1;
+        if let Err(error) = result {
+            self.failed.push((name, error.clone()));
+        }
+    }
+
+    pub fn dismiss_failed(&mut self) {
+        self.failed.clear();
+    }
+
+    fn is_idle(&self) -> bool {
+        self.waiting.is_empty() && self.running == 0
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+// the files that didn't work, the batch carries on without them
+pub fn failures(queue: &ImageQueue) -> Option<Node<Msg>> {
+    if queue.failed.is_empty() {
+        return None;
+    }
+    Some(div![
+        C!["notification is-danger is-light"],
+        attrs! {At::from("role") => "alert"},
+        button![
+            C!["delete"],
+            attrs! {At::AriaLabel => "dismiss"},
+            ev(Ev::Click, |_| Msg::DismissImageErrors),
+        ],
+        p![if queue.failed.len() == 1 {
+            "This file couldn't be made into a card:".to_string()
+        } else {
+            format!(
+                "These {} files couldn't be made into cards:",
+                queue.failed.len()
+            )
+        }],
+        ul![queue
+            .failed
+            .iter()
+            .map(|(name, error)| li![strong![name], format!(": {}", error)])],
+    ])
+}
+
+// a progress bar while a batch is being worked on
+pub fn progress(queue: &ImageQueue) -> Option<Node<Msg>> {
+    if queue.is_idle() {
+        return None;
+    }
+    Some(div![
+        style![St::Width => "80%"],
+        progress![
+            C!["progress is-small is-info"],
+            attrs! {At::Value => queue.done, At::Max => queue.total},
+        ],
+        p![
+            C!["help"],
+            format!("{} of {} images processed", queue.done, queue.total)
+        ],
+    ])
+}
@@ -0,0 +1,136 @@
+// thumbnails made on a web worker, so dropping a pile of big photos doesn't freeze the page while
+// they're decoded and resized. the worker (worker.js) loads this same wasm and calls
+// `thumbnail::worker_thumbnail`. wherever a worker can't start (an exported game opened as a
+// file, an older browser) the thumbnail is made right here like before
+use crate::thumbnail;
+use seed::{prelude::*, wasm_bindgen_futures::JsFuture};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use web_sys::{MessageEvent, Worker, WorkerOptions};
+
+const SCRIPT: &str = "/matching-seed/worker.js";
+
+// the promise resolvers for pictures the worker is still on, by request id
+type Waiting = Rc<RefCell<BTreeMap<u32, js_sys::Function>>>;
+
+struct Thumbnailer {
+    worker: Worker,
+    next_id: u32,
+    waiting: Waiting,
+    broken: Rc<RefCell<bool>>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut(JsValue)>,
+}
+
+thread_local! {
+    // started the first time it's needed
+    static THUMBNAILER: RefCell<Option<Thumbnailer>> = const { RefCell::new(None) };
+}
+
+impl Thumbnailer {
+    fn start() -> Option<Self> {
+        let options = WorkerOptions::new();
+        options.set_name("thumbnails");
+        // (web_sys doesn't have the type option)
+        js_sys::Reflect::set(&options, &"type".into(), &"module".into()).ok()?;
+        let worker = Worker::new_with_options(SCRIPT, &options).ok()?;
+
+        let waiting: Waiting = Rc::default();
+        let on_message = {
+            let waiting = Rc::clone(&waiting);
+            Closure::wrap(Box::new(move |event: MessageEvent| {
+                let data = event.data();
+                let id = js_sys::Reflect::get(&data, &"id".into())
+                    .ok()
+                    .and_then(|id| id.as_f64());
+                // ids are small counters
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let resolve = id.and_then(|id| waiting.borrow_mut().remove(&(id as u32)));
+                if let Some(resolve) = resolve {
+                    let _garbage = resolve.call1(&JsValue::NULL, &data);
+                }
+            }) as Box<dyn FnMut(MessageEvent)>)
+        };
+
+        // the script didn't load or the worker fell over: everything waiting gets made here
+        let broken = Rc::new(RefCell::new(false));
+        let on_error = {
+            let (waiting, broken) = (Rc::clone(&waiting), Rc::clone(&broken));
+            Closure::wrap(Box::new(move |_: JsValue| {
+                *broken.borrow_mut() = true;
+                for resolve in std::mem::take(&mut *waiting.borrow_mut()).into_values() {
+                    let _garbage = resolve.call1(&JsValue::NULL, &JsValue::UNDEFINED);
+                }
+            }) as Box<dyn FnMut(JsValue)>)
+        };
+
+        worker.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        worker.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        Some(Self {
+            worker,
+            next_id: 0,
+            waiting,
+            broken,
+            _on_message: on_message,
+            _on_error: on_error,
+        })
+    }
+
+    // the worker's answer, once it comes
+    fn send(&mut self, bytes: &[u8]) -> Option<js_sys::Promise> {
+        if *self.broken.borrow() {
+            return None;
+        }
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+
+        let bytes = js_sys::Uint8Array::from(bytes);
+        let message = js_sys::Object::new();
+        js_sys::Reflect::set(&message, &"id".into(), &id.into()).ok()?;
+        js_sys::Reflect::set(&message, &"bytes".into(), &bytes).ok()?;
+
+        let waiting = Rc::clone(&self.waiting);
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            waiting.borrow_mut().insert(id, resolve);
+        });
+        // hand the bytes over instead of copying them
+        let transfer = js_sys::Array::of1(&bytes.buffer());
+        if self
+            .worker
+            .post_message_with_transfer(&message, &transfer)
+            .is_err()
+        {
+            self.waiting.borrow_mut().remove(&id);
+            return None;
+        }
+        Some(promise)
+    }
+}
+
+// a thumbnail data url for the picture, like `thumbnail::data_url` but off the main thread
+pub async fn data_url(bytes: Vec<u8>) -> Result<String, String> {
+    let promise = THUMBNAILER.with(|thumbnailer| {
+        let mut thumbnailer = thumbnailer.borrow_mut();
+        if thumbnailer.is_none() {
+            *thumbnailer = Thumbnailer::start();
+        }
+        thumbnailer.as_mut()?.send(&bytes)
+    });
+    if let Some(promise) = promise {
+        let reply = JsFuture::from(promise).await.unwrap_or(JsValue::UNDEFINED);
+        let field = |name: &str| {
+            js_sys::Reflect::get(&reply, &name.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+        if let Some(url) = field("url") {
+            return Ok(url);
+        }
+        if let Some(error) = field("error") {
+            return Err(error);
+        }
+    }
+    // no worker, or it broke before answering
+    thumbnail::data_url(&bytes).map_err(|error| error.to_string())
+}
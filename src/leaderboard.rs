@@ -0,0 +1,113 @@
+// the ten best games on each saved deck, kept in the browser. fewer moves is better, and the
+// time breaks ties
+use crate::storage;
+use seed::{prelude::*, *};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const TOP: usize = 10;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub name: String,
+    pub moves: u32,
+    pub seconds: f64,
+    // js time (ms) the game was won at
+    #[serde(default)]
+    pub date: f64,
+}
+
+impl Entry {
+    fn beats(&self, other: &Self) -> bool {
+        (self.moves, self.seconds) < (other.moves, other.seconds)
+    }
+}
+
+#[derive(Default)]
+pub struct Leaderboards {
+    decks: BTreeMap<String, Vec<Entry>>,
+}
+
+impl Leaderboards {
+    pub fn load() -> Self {
+        Self {
+            decks: storage::load(storage::LEADERBOARDS).unwrap_or_default(),
+        }
+    }
+
+    // add a result, and say where it placed if it made the table
+    pub fn record(&mut self, deck: &str, entry: Entry) -> Option<usize> {
+        let entries = self.decks.entry(deck.to_string()).or_default();
+        let place = entries
+            .iter()
+            .position(|other| entry.beats(other))
+            .unwrap_or(entries.len());
+        if place >= TOP {
+            return None;
+        }
+        entries.insert(place, entry);
+        entries.truncate(TOP);
+        storage::save(storage::LEADERBOARDS, &self.decks);
+        Some(place)
+    }
+
+    pub fn top(&self, deck: &str) -> &[Entry] {
+        self.decks.get(deck).map_or(&[], Vec::as_slice)
+    }
+
+    // a deck was deleted or renamed away
+    pub fn forget(&mut self, deck: &str) {
+        if self.decks.remove(deck).is_some() {
+            storage::save(storage::LEADERBOARDS, &self.decks);
+        }
+    }
+}
+
+pub fn title(deck: &str) -> String {
+    format!("🏆 Best games on \"{}\"", deck)
+}
+
+fn date(ms: f64) -> String {
+    js_sys::Date::new(&JsValue::from_f64(ms))
+        .to_locale_date_string("default", &JsValue::UNDEFINED)
+        .into()
+}
+
+// ------ ------
+//     View
+// ------ ------
+// the table for one deck, with `highlight` (a place from `record`) in bold
+pub fn view<Ms>(title: &str, entries: &[Entry], highlight: Option<usize>) -> Node<Ms> {
+    div![
+        C!["block"],
+        h2![C!["title is-5"], title],
+        if entries.is_empty() {
+            p!["no games won on this deck yet"]
+        } else {
+            table![
+                C!["table is-narrow is-striped"],
+                thead![tr![
+                    th!["#"],
+                    th!["name"],
+                    th!["moves"],
+                    th!["time"],
+                    th!["date"]
+                ]],
+                tbody![entries.iter().enumerate().map(|(place, entry)| {
+                    tr![
+                        C![IF!(highlight == Some(place) => "is-selected")],
+                        td![place + 1],
+                        td![if entry.name.is_empty() {
+                            "?"
+                        } else {
+                            &entry.name
+                        }],
+                        td![entry.moves],
+                        td![format!("{:.1}s", entry.seconds)],
+                        td![IF!(entry.date > 0.0 => date(entry.date))],
+                    ]
+                })],
+            ]
+        },
+    ]
+}
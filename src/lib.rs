@@ -1,14 +1,90 @@
 #![allow(clippy::wildcard_imports)]
-use image::{DynamicImage, ImageFormat};
+use notifications::Level;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
 use seed::{prelude::*, *};
-use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use ulid::Ulid;
-use web_sys::{self, DragEvent, Event, FileList};
+use web_sys::{self, DragEvent, Event};
+
+mod animation;
+mod audio;
+mod avatar;
+mod camera;
+mod challenge;
+mod clipboard;
+mod clock;
+mod countdown;
+mod csv;
+mod deck;
+mod download;
+mod draft;
+mod drop_zone;
+mod game;
+mod generators;
+mod global_leaderboard;
+mod history;
+mod hot_seat;
+mod idb;
+mod image_gen;
+mod image_queue;
+mod image_worker;
+mod leaderboard;
+mod library;
+mod list_board;
+mod lives;
+mod lookup;
+mod mid_game_edit;
+mod notifications;
+mod photo_edit;
+mod pinch;
+mod preload;
+mod race;
+mod review;
+mod rounds;
+mod route;
+mod saved_game;
+mod session;
+mod settings;
+mod share;
+mod skins;
+mod speech;
+mod speedrun;
+mod spelling;
+mod standalone;
+mod stats;
+mod stickers;
+mod stock_photos;
+mod storage;
+mod sync;
+mod theme;
+mod thumbnail;
+mod toddler;
+mod trace;
+mod visible_rows;
+mod webhook;
+mod wizard;
+
+// exported for the thumbnail worker
+pub use thumbnail::worker_thumbnail;
 
-const THUMB_SIZE: u32 = 250;
 const COLUMNS_NUMBER: usize = 6;
+// the column counts to pick from in the settings
+const COLUMN_CHOICES: std::ops::RangeInclusive<usize> = 2..=8;
+// as far as zooming out can go
+const MAX_COLUMNS: usize = 12;
+// card size choices during play, in percent
+const CARD_ZOOM_RANGE: (usize, usize) = (50, 200);
+// how long the win screen shows before continuous play deals a new board
+const CONTINUOUS_WIN_MS: u32 = 4000;
+// how long a found pair shows big in the middle of the screen
+const MATCH_HIGHLIGHT_MS: u32 = 1500;
+// how long "card deleted, undo?" stays up
+const DELETE_NOTICE_MS: u32 = 8000;
+// cards per page of the editor table
+const EDITOR_PAGE_SIZE: usize = 25;
 
 const QUESTION_IMG: &str = "/matching-seed/q.png";
 const ARROW_IMAGE: &str = "/matching-seed/arrow.png";
@@ -16,14 +92,112 @@ const ARROW_IMAGE: &str = "/matching-seed/arrow.png";
 // ------ ------
 //     Init
 // ------ ------
-fn init(_: Url, _: &mut impl Orders<Msg>) -> Model {
-    Model::default()
+fn init(url: Url, orders: &mut impl Orders<Msg>) -> Model {
+    // voices show up async in some browsers, so refresh the list when they do
+    let msg_sender = orders.msg_sender();
+    speech::on_voices_changed(move || msg_sender(Some(Msg::VoicesChanged)));
+
+    // only the editor rows near the screen get their pictures drawn
+    let msg_sender = orders.msg_sender();
+    let row_observer =
+        visible_rows::Observer::new(move |rows| msg_sender(Some(Msg::RowsVisible(rows))));
+
+    // ctrl+z and ctrl+y in the editor, the arrow keys on the board
+    orders.stream(streams::window_event(Ev::KeyDown, |event| {
+        let event = event.unchecked_into::<web_sys::KeyboardEvent>();
+        let msg = history_shortcut(&event).or_else(|| board_key(&event))?;
+        event.prevent_default();
+        Some(msg)
+    }));
+
+    // pictures on the clipboard become cards. pasting text into a box works as usual
+    orders.stream(streams::window_event(Ev::Paste, |event| {
+        // (web_sys only has ClipboardEvent behind its unstable apis)
+        let files = js_sys::Reflect::get(&event, &JsValue::from_str("clipboardData"))
+            .ok()?
+            .dyn_into::<web_sys::DataTransfer>()
+            .ok()?
+            .files()?;
+        let files: Vec<web_sys::File> = (0..files.length())
+            .filter_map(|index| files.get(index))
+            .collect();
+        if files.is_empty() {
+            return None;
+        }
+        event.prevent_default();
+        Some(Msg::Paste(files))
+    }));
+
+    // a refresh or a closed tab shouldn't lose the last second of edits
+    orders.stream(streams::window_event(Ev::BeforeUnload, |_| Msg::SaveDraft));
+
+    let mut model = Model {
+        voices: speech::available_voices(),
+        row_observer,
+        library: library::Model::load(),
+        stats: stats::Stats::load(),
+        stickers: stickers::Model::load(),
+        leaderboards: leaderboard::Leaderboards::load(),
+        best_times: clock::BestTimes::load(),
+        ..Model::default()
+    };
+    settings::load(&mut model);
+
+    // an exported game file has its deck built in, so go straight to playing
+    if let Some(deck) = standalone::embedded_deck() {
+        model.speech = deck.speech();
+        model.answer_policy = deck.answer_policy;
+        model.set_cards(deck.cards);
+        orders.send_msg(Msg::StartGame);
+    } else if let Some(challenge) = challenge::Challenge::from_url(&url) {
+        // a friend's challenge: their cards, shuffled the same way
+        let deck = challenge.deck.clone();
+        model.speech = deck.speech();
+        model.answer_policy = deck.answer_policy;
+        model.set_cards(deck.cards);
+        model.mode = game::Mode::Classic;
+        model.replay = Some(model.cards().map(|card| card.id).collect());
+        model.next_seed = Some(challenge.seed);
+        model.challenge = Some(challenge);
+        orders.send_msg(Msg::StartGame);
+    } else {
+        // a colleague's deck goes in with the saved decks to be picked from, the draft is still
+        // the editor's (the link's page isn't one, so it's replaced below and a refresh doesn't
+        // add it again)
+        if let Some(shared) = share::Shared::from_url(&url) {
+            model.library.add_shared(shared.name, shared.deck);
+        }
+        // the word list as it was last time, and offer to pick up a game left unfinished
+        orders.perform_cmd(async { Msg::DraftLoaded(draft::load().await) });
+        model.resumable = saved_game::load();
+        // a refresh in the middle of a game goes right back to it
+        let route = route::Page::from_url(&url);
+        model.page = match route {
+            Some(route::Page::Play) if model.resumable.is_some() => {
+                orders.send_msg(Msg::ResumeGame);
+                route::Page::Play
+            }
+            Some(route::Page::Settings) => {
+                model.settings_open = true;
+                route::Page::Settings
+            }
+            _ => route::Page::Edit,
+        };
+        // (a game that's gone can't be gone back to)
+        if route != Some(model.page) {
+            model.page.replace();
+        }
+    }
+    orders.subscribe(Msg::UrlChanged);
+    orders.perform_cmd(async { Msg::DecksLoaded(library::Model::load_decks().await) });
+
+    model
 }
 
 // ------ ------
 //     Models
 // ------ ------
-#[derive(PartialOrd, PartialEq, Clone)]
+#[derive(PartialOrd, PartialEq, Clone, Serialize, Deserialize)]
 enum CardState {
     FaceUp,
     FaceDown,
@@ -31,34 +205,458 @@ enum CardState {
 
 enum NewCardType {
     OnePhoto(String),
+    // the same text on both tiles
+    Text(String),
+    // text for both tiles of a pair
+    TextPair(String, String),
+    // a picture with text under it, on both tiles
+    PhotoWithText(String, String),
+    // a picture on one tile and text on the other
+    PhotoPair(String, String),
+    // text on one tile, and the other one speaks
+    SoundPair(String, String),
+    // a sound clip, with text (like the file's name) to show
+    AudioWithText(String, String),
     Empty,
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 struct Card {
     text: Option<String>,
+    // optional text for the partner tile (like a translation or definition)
+    #[serde(default)]
+    text_b: Option<String>,
+    // words read out loud when the partner tile flips, instead of showing text
+    #[serde(default)]
+    sound: Option<String>,
     photo: Option<String>,
+    // a blurry few pixels of the photo to show while it loads
+    #[serde(default)]
+    preview: Option<String>,
+    // a sound clip (as a data url) that plays when either tile turns over
+    #[serde(default)]
+    audio: Option<String>,
     id: Ulid,
 }
 
+impl Card {
+    // why the card can't be played, if it can't
+    fn skip_reason(&self) -> Option<&'static str> {
+        let has = |field: &Option<String>| field.as_deref().is_some_and(|text| !text.is_empty());
+        let blank =
+            |field: &Option<String>| field.as_deref().is_none_or(|text| text.trim().is_empty());
+
+        if !has(&self.text) && !has(&self.photo) && !has(&self.text_b) && !has(&self.sound) {
+            Some("it has no picture, text or sound")
+        } else if self.photo.is_none()
+            && blank(&self.text)
+            && blank(&self.text_b)
+            && blank(&self.sound)
+        {
+            Some("its text is only spaces")
+        } else {
+            None
+        }
+    }
+}
+
+// which side of a pair a tile shows
+// cards without text_b just get two A tiles
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+enum PairHalf {
+    A,
+    B,
+}
+
 struct PlayedCard {
     card: Card,
+    half: PairHalf,
     displayed: CardState,
     matched: bool,
 }
 
+#[derive(Default)]
 struct Model {
     game_started: bool,
     words_list: BTreeMap<Ulid, Card>,
+    // the order the cards were put in by dragging them around the editor. cards that aren't in
+    // it go after the rest, oldest first, and ids of deleted cards are just skipped (so undo
+    // puts a card back where it was)
+    card_order: Vec<Ulid>,
+    // the editor's own list and its order, put aside while a game plays other cards (several
+    // saved decks mixed together, a saved or followed game). it comes back when the game ends
+    set_aside: Option<(BTreeMap<Ulid, Card>, Vec<Ulid>)>,
+    // the editor row being dragged, and the one it's over
+    moving_card: Option<Ulid>,
+    move_target: Option<Ulid>,
     board: Vec<PlayedCard>,
     last: Option<Ulid>,
     needs_reset: bool,
+    // a game from last time that can be picked up again
+    resumable: Option<saved_game::SavedGame>,
+    // the word list is the user's own (not a challenge's or an exported game's), so it's kept
+    // in local storage as it changes
+    saves_words: bool,
+    // which page of the editor table is showing
+    editor_page: usize,
+    // cards whose photo has loaded, the others show a skeleton
+    loaded_photos: BTreeSet<Ulid>,
+    // editor rows that are scrolled into view, None if the browser can't tell
+    row_observer: Option<visible_rows::Observer>,
+    visible_rows: BTreeSet<Ulid>,
+    // the board stays hidden until its pictures are decoded
+    preparing_board: bool,
+    // kept so the decoded pictures stay in the browser's cache
+    decoded_images: Vec<web_sys::HtmlImageElement>,
+    // tiles turning over and the win screen fading in
+    animations: animation::Animations,
+    // asking what to do about unplayable cards before the game starts
+    confirm_skipped: bool,
+    // the board as it'll look, shown in the editor
+    board_preview_open: bool,
+    // a draft save is already waiting to happen
+    draft_pending: bool,
+    // cards changed that aren't the user's own list, see draft::save_unsaved
+    unsaved_edits: bool,
+    // the unsaved draft from last time, until it's restored or thrown away
+    draft: Option<draft::Draft>,
+    // undo/redo for changes to the word list
+    history: history::History,
+    // the page the address bar says, see route.rs
+    page: route::Page,
+    // the tile the arrow keys are on, once they've been pressed
+    focused_tile: Option<usize>,
+    // the card just deleted, offered back for a little while
+    deleted_card: Option<(Ulid, String)>,
+    // the same game shown in another window
+    link: Option<sync::Link>,
+
+    // deck language and voice for reading cards out loud
+    speech: speech::SpeechSettings,
+    voices: Vec<speech::Voice>,
+    settings_open: bool,
+
+    // deal a new board by itself after every win
+    continuous: bool,
+    mode: game::Mode,
+    difficulty: game::Difficulty,
+    miss_reset: game::MissReset,
+    // comes and goes with the deck
+    answer_policy: game::AnswerPolicy,
+    // type the word after every pair found, and the word that was just found
+    spelling_practice: bool,
+    spelling: Option<spelling::Spelling>,
+    // how many pairs to deal from the deck, empty for all of them
+    game_pairs: String,
+    // the cards to deal next time instead of a fresh pick, for playing the same pairs again
+    replay: Option<BTreeSet<Ulid>>,
+    // where every tile was last game, to deal the exact same board for a rematch
+    rematch_layout: Option<Vec<(Ulid, PairHalf)>>,
+    // what the board was shuffled with, None if it can't be dealt again from a seed
+    board_seed: Option<u64>,
+    // the seed to shuffle the next board with, instead of a random one
+    next_seed: Option<u64>,
+    // how long the last won game took
+    finished_seconds: Option<f64>,
+    // the challenge this game came from, and how copying a challenge link went
+    challenge: Option<challenge::Challenge>,
+    challenge_status: Option<Result<(), String>>,
+    // first tiles turned over so far, so a flip back timer can tell if it's stale
+    flips: u32,
+    // the pair that was just found, shown big for a moment
+    highlight: Option<Ulid>,
+    // wrong guesses per card this game, for the review at the end
+    misses: BTreeMap<Ulid, u32>,
+    // times each board position was turned over this game
+    tile_flips: Vec<u32>,
+    reviewing: bool,
+    // how far through the rounds the player is, in rounds mode
+    campaign: Option<rounds::Campaign>,
+    // wrong guesses allowed in lives mode, empty for the default
+    lives_setting: String,
+    lives: Option<lives::Lives>,
+    // seconds on the clock in beat the clock mode, empty for the default
+    countdown_setting: String,
+    countdown: Option<countdown::Countdown>,
+    // the second board in race mode
+    race: Option<race::Race>,
+    // names for hot seat mode, in turn order. empty ones are numbered
+    player_names: Vec<String>,
+    // the who's playing page before a hot seat game
+    setting_up_players: bool,
+    hot_seat: Option<hot_seat::HotSeat>,
+    // minutes a session lasts, empty for no limit
+    session_minutes: String,
+    session: Option<session::Session>,
+    session_timer: Option<StreamHandle>,
+
+    // millisecond timing with splits, when turned on in the settings
+    speedrun_enabled: bool,
+    speedrun: Option<speedrun::Speedrun>,
+    speedrun_timer: Option<StreamHandle>,
+    // the everyday game clock, the last time it ticked, and the best times it's seen
+    clock: Option<StreamHandle>,
+    clock_now: f64,
+    best_times: clock::BestTimes,
+    new_best_time: bool,
+
+    // a timestamped record of every click, for researchers, when turned on in the settings
+    tracing: bool,
+    trace: Option<trace::Trace>,
+
+    // how the game board looks
+    skin: skins::Skin,
+    theme: theme::Theme,
+
+    // searching stock photo sites for new cards
+    stock_photos: stock_photos::StockPhotoSettings,
+    photo_search: stock_photos::Search,
+
+    // drawing pictures for cards with an image generation api
+    image_gen: image_gen::ImageGenSettings,
+    generating: BTreeSet<Ulid>,
+
+    // filling in partner text from a dictionary/translation api
+    lookup: lookup::LookupSettings,
+    suggesting: BTreeSet<Ulid>,
+    batch_lookup: Option<lookup::BatchLookup>,
+
+    stickers: stickers::Model,
+    leaderboards: leaderboard::Leaderboards,
+    // the deck the last win counted for, and where it placed on the leaderboard
+    leaderboard_result: Option<(String, Option<usize>)>,
+    // the school wide leaderboard, if there's a server for it
+    global_leaderboard: global_leaderboard::GlobalLeaderboardSettings,
+    global_ranking: Option<Result<Vec<leaderboard::Entry>, String>>,
+    avatar: Option<avatar::Avatar>,
+    avatar_error: Option<String>,
+
+    // big cards and no way off the board without holding the exit button
+    toddler: bool,
+    // card size on the board in percent, None for the usual size
+    card_zoom: Option<usize>,
+    // cards across the board, None for the usual number
+    column_count: Option<usize>,
+    // pinching and dragging the board on touch screens
+    pinch: pinch::Model,
+    // cards with a picture and a word are dealt as a picture tile and a word tile to connect
+    picture_word_pairs: bool,
+    // a numbered list of cards instead of the grid, for screen readers, and what it last said
+    list_board: bool,
+    announcement: String,
+    holding_exit: bool,
+    // times the exit button was pressed, so letting go and pressing again restarts the wait
+    exit_holds: u32,
+
+    // where finished games get reported, and how the last report went
+    webhook: webhook::WebhookSettings,
+    webhook_result: Option<Result<(), String>>,
+    // when the board was dealt (or picked back up), for the report
+    game_started_at: f64,
+
+    // which cards get missed the most, over every game played here
+    stats: stats::Stats,
+    // decks kept in the browser
+    library: library::Model,
+    // built in deck generators
+    generators: generators::Model,
+    // the "what kind of deck?" templates show on an empty deck until one is picked
+    wizard_dismissed: bool,
+
+    // problems with a card's generate/suggest request, shown in its row
+    card_errors: BTreeMap<Ulid, String>,
+    // the game is paused to fix a card
+    editing_mid_game: bool,
+    // js time (ms) it was paused at
+    paused_at: f64,
+    // the card a sound is being recorded for
+    recording: Option<(Ulid, audio::Recorder)>,
+    // the camera, while photos are being taken for new cards
+    camera: Option<camera::Camera>,
+    // a card's picture being turned or trimmed
+    photo_edit: Option<photo_edit::PhotoEdit>,
 
     // for drag and drop
     drop_zone_active: bool,
+    // what the drag over the drop zone is carrying
+    drag_summary: drop_zone::DragSummary,
+    // dropped pictures waiting to become cards
+    image_queue: image_queue::ImageQueue,
+
+    // the messages in the corner
+    notifications: notifications::Notifications,
 }
 
 impl Model {
+    // create a new card based on NewCardType
+    fn add_card(&mut self, card_type: NewCardType) {
+        let new_id = Ulid::new();
+
+        let mut new_card = match card_type {
+            NewCardType::AudioWithText(audio, text) => Card {
+                id: new_id,
+                photo: None,
+                text: Some(text),
+                text_b: None,
+                sound: None,
+                preview: None,
+                audio: Some(audio),
+            },
+            NewCardType::Empty => Card {
+                id: new_id,
+                photo: None,
+                text: None,
+                text_b: None,
+                sound: None,
+                preview: None,
+                audio: None,
+            },
+            NewCardType::OnePhoto(content) => Card {
+                id: new_id,
+                photo: Some(content),
+                text: None,
+                text_b: None,
+                sound: None,
+                preview: None,
+                audio: None,
+            },
+            NewCardType::Text(text) => Card {
+                id: new_id,
+                photo: None,
+                text: Some(text),
+                text_b: None,
+                sound: None,
+                preview: None,
+                audio: None,
+            },
+            NewCardType::PhotoWithText(photo, text) => Card {
+                id: new_id,
+                photo: Some(photo),
+                text: Some(text),
+                text_b: None,
+                sound: None,
+                preview: None,
+                audio: None,
+            },
+            NewCardType::PhotoPair(photo, text_b) => Card {
+                id: new_id,
+                photo: Some(photo),
+                text: None,
+                text_b: Some(text_b),
+                sound: None,
+                preview: None,
+                audio: None,
+            },
+            NewCardType::SoundPair(text, sound) => Card {
+                id: new_id,
+                photo: None,
+                text: Some(text),
+                text_b: None,
+                sound: Some(sound),
+                preview: None,
+                audio: None,
+            },
+            NewCardType::TextPair(text, text_b) => Card {
+                id: new_id,
+                photo: None,
+                text: Some(text),
+                text_b: Some(text_b),
+                sound: None,
+                preview: None,
+                audio: None,
+            },
+        };
+        new_card.preview = new_card.photo.as_deref().and_then(thumbnail::preview_url);
+        self.words_list.entry(new_id).or_insert(new_card);
+    }
+
+    // the cards in the editor's order
+    fn cards(&self) -> impl Iterator<Item = &Card> + '_ {
+        in_order(&self.words_list, &self.card_order)
+    }
+
+    // the editor's cards in its order, even while a game is playing others
+    fn editor_cards(&self) -> impl Iterator<Item = &Card> + '_ {
+        let (words_list, card_order) = self
+            .set_aside
+            .as_ref()
+            .map_or((&self.words_list, &self.card_order), |(words, order)| {
+                (words, order)
+            });
+        in_order(words_list, card_order)
+    }
+
+    // a whole new list of cards, in the order given
+    fn set_cards(&mut self, cards: Vec<Card>) {
+        self.words_list.clear();
+        self.card_order.clear();
+        self.add_cards(cards);
+    }
+
+    // more cards after the ones already in the list
+    fn add_cards(&mut self, cards: Vec<Card>) {
+        add_to(&mut self.words_list, &mut self.card_order, cards);
+    }
+
+    // play these cards instead of the editor's, which wait in `set_aside` until the game ends.
+    // if they're already put aside (the next board of a followed game) they stay as they were
+    fn play_other_cards(&mut self, words: BTreeMap<Ulid, Card>, order: Vec<Ulid>) {
+        let editor = (
+            std::mem::replace(&mut self.words_list, words),
+            std::mem::replace(&mut self.card_order, order),
+        );
+        self.set_aside.get_or_insert(editor);
+    }
+
+    // the editor's list, wherever it is right now
+    fn editor_list(&mut self) -> (&mut BTreeMap<Ulid, Card>, &mut Vec<Ulid>) {
+        match &mut self.set_aside {
+            Some((words, order)) => (words, order),
+            None => (&mut self.words_list, &mut self.card_order),
+        }
+    }
+
+    // a copy of a card right after it, to change into a variant without adding the picture again
+    fn duplicate_card(&mut self, id: Ulid) {
+        let Some(card) = self.words_list.get(&id) else {
+            return;
+        };
+        let copy = Card {
+            id: Ulid::new(),
+            ..card.clone()
+        };
+        let mut order: Vec<Ulid> = self.cards().map(|card| card.id).collect();
+        let after = order
+            .iter()
+            .position(|other| *other == id)
+            .map_or(order.len(), |at| at + 1);
+        order.insert(after, copy.id);
+        self.card_order = order;
+        self.words_list.insert(copy.id, copy);
+    }
+
+    // put a card where another one is, the cards in between shift over to make room
+    fn move_card(&mut self, id: Ulid, to: Ulid) {
+        let mut order: Vec<Ulid> = self.cards().map(|card| card.id).collect();
+        let position = |id: Ulid| order.iter().position(|other| *other == id);
+        if let (Some(from), Some(to)) = (position(id), position(to)) {
+            order.remove(from);
+            order.insert(to, id);
+            self.card_order = order;
+        }
+    }
+
+    // cards across the board, fewer when the cards are zoomed in
+    fn columns(&self) -> usize {
+        let columns = if self.toddler {
+            toddler::COLUMNS
+        } else {
+            self.column_count.unwrap_or(COLUMNS_NUMBER)
+        };
+        let zoom = self.card_zoom.unwrap_or(100);
+        ((columns * 100 + zoom / 2) / zoom).clamp(1, MAX_COLUMNS)
+    }
+
     fn all_face_down(&mut self) {
         for card in &mut self.board {
             card.displayed = CardState::FaceDown;
@@ -66,330 +664,2783 @@ impl Model {
         self.needs_reset = false;
         self.last = None;
     }
-}
 
-impl Default for Model {
-    fn default() -> Self {
-        Self {
-            game_started: false,
-            words_list: BTreeMap::new(),
-            board: Vec::new(),
-            last: None,
-            needs_reset: false,
+    // cards new_board will leave out, and why
+    fn skipped_cards(&self) -> Vec<(&Card, &'static str)> {
+        self.cards()
+            .filter_map(|card| Some((card, card.skip_reason()?)))
+            .collect()
+    }
+
+    // two tiles for every card, in deck order
+    fn new_board(&self) -> Vec<PlayedCard> {
+        let mut new_board: Vec<PlayedCard> = vec![];
+        for card_pair in self.cards() {
+            // skip the card if there's nothing to show on it
+            if card_pair.skip_reason().is_some() {
+                continue;
+            }
+
+            let card_pair = &dealt_card(card_pair, self.picture_word_pairs);
+            // a card with partner text (or sound) gets one tile for each side
+            let second_half = if card_pair.text_b.is_some() || card_pair.sound.is_some() {
+                PairHalf::B
+            } else {
+                PairHalf::A
+            };
+
+            new_board.push(PlayedCard {
+                displayed: CardState::FaceDown,
+                matched: false,
+                card: card_pair.clone(),
+                half: PairHalf::A,
+            });
+            new_board.push(PlayedCard {
+                displayed: CardState::FaceDown,
+                matched: false,
+                card: card_pair.clone(),
+                half: second_half,
+            });
+        }
+        new_board
+    }
+
+    // every pair found
+    fn all_matched(&self) -> bool {
+        !self.board.is_empty() && self.board.iter().all(|tile| tile.matched)
+    }
 
-            drop_zone_active: false,
+    // back to the editor, keeping the words
+    fn end_game(&mut self) {
+        self.editing_mid_game = false;
+        self.clock = None;
+        self.trace = None;
+        self.challenge = None;
+        self.speedrun = None;
+        self.speedrun_timer = None;
+        self.highlight = None;
+        self.spelling = None;
+        self.race = None;
+        self.hot_seat = None;
+        self.misses.clear();
+        self.tile_flips.clear();
+        self.reviewing = false;
+        self.campaign = None;
+        self.lives = None;
+        self.countdown = None;
+        self.session = None;
+        self.session_timer = None;
+        self.game_started = false;
+        self.board = vec![];
+        self.last = None;
+        self.needs_reset = false;
+        if let Some((words, order)) = self.set_aside.take() {
+            self.words_list = words;
+            self.card_order = order;
         }
     }
 }
 
+fn in_order<'a>(
+    words_list: &'a BTreeMap<Ulid, Card>,
+    card_order: &'a [Ulid],
+) -> impl Iterator<Item = &'a Card> + 'a {
+    let mut seen = BTreeSet::new();
+    card_order
+        .iter()
+        .chain(words_list.keys())
+        .filter(move |id| seen.insert(**id))
+        .filter_map(move |id| words_list.get(id))
+}
+
+fn add_to(words_list: &mut BTreeMap<Ulid, Card>, card_order: &mut Vec<Ulid>, cards: Vec<Card>) {
+    if card_order.is_empty() {
+        *card_order = words_list.keys().copied().collect();
+    }
+    for card in cards {
+        card_order.push(card.id);
+        words_list.insert(card.id, card);
+    }
+}
+
 // ------ ------
 //    Update
 // ------ ------
 enum Msg {
     NewCard(NewCardType),
-    UpdateCardText { id: Ulid, text: String },
+    UpdateCardText {
+        id: Ulid,
+        text: String,
+    },
+    UpdateCardTextB {
+        id: Ulid,
+        text: String,
+    },
     DeleteCard(Ulid),
+    DuplicateCard(Ulid),
+    EditPhoto(Ulid),
+    TurnPhoto(bool),
+    TrimPhoto(photo_edit::Edge, String),
+    ApplyPhotoEdit,
+    CancelPhotoEdit,
+    StartMovingCard(Ulid),
+    MoveCardOver(Ulid),
+    MoveCard(Ulid),
+    StopMovingCard,
+    HideDeleteNotice(Ulid),
     GuessCard(usize),
     ShowHideAll,
     StartGame,
+    // answers to the "some cards will be skipped" question
+    FixSkipped,
+    DeleteSkipped,
+    StartSkipping,
     ExitGame,
+    // after a win: back to this deck's cards, or on to another saved deck
+    EditDeck,
+    PickAnotherDeck,
     ResetClick,
+    UrlChanged(subs::UrlChanged),
+    // the key, and the tile it was pressed on if one had the focus
+    BoardKey(String, Option<usize>),
+    ResumeGame,
+    DiscardSavedGame,
+    SaveDraft,
+    DraftLoaded(Option<draft::Draft>),
+    UnsavedDraftLoaded(Option<draft::Draft>),
+    RestoreDraft,
+    DiscardDraft,
+    DecksLoaded(BTreeMap<String, deck::Deck>),
+    Undo,
+    Redo,
+    AnimationFrame(f64),
+    ToggleContinuous,
+    SetMode(String),
+    SetLives(String),
+    SetCountdown(String),
+    SetHotSeatName(usize, String),
+    AddHotSeatPlayer,
+    RemoveHotSeatPlayer(usize),
+    CancelPlayerSetup,
+    SetDifficulty(String),
+    SetMissReset(String),
+    MissFlipBack(u32),
+    FlipBack(u32),
+    HideHighlight(Ulid),
+    ToggleReview,
+    NextRound,
+    NewCampaign,
+    ContinueAfterWin,
+    SetSessionMinutes(String),
+    SessionTick,
+    NewSession,
+    BoardDecoded(Vec<web_sys::HtmlImageElement>),
+    RowsVisible(Vec<(Ulid, bool)>),
+    ObserveRows,
+    PhotoLoaded(Ulid),
+    SetEditorPage(usize),
+    ToggleBoardPreview,
+    ShareGame,
+    JoinSharedGame,
+    LeaveSharedGame,
+    Synced(sync::Message),
+
+    ToggleSettings,
+    VoicesChanged,
+    SetSpeechLang(String),
+    SetSpeechVoice(String),
+    PreviewVoice,
+    Say(String),
+    ToggleReadWords,
+    PlayClip(String),
+    RecordAudio(Ulid),
+    RecordingStarted {
+        id: Ulid,
+        result: Result<audio::Recorder, String>,
+    },
+    StopRecording,
+    AudioRecorded {
+        id: Ulid,
+        result: Result<String, String>,
+    },
+    AttachAudio {
+        id: Ulid,
+        file: web_sys::File,
+    },
+    RemoveAudio(Ulid),
+    AudioFileDropped(web_sys::File),
+    SetPhotoProvider(String),
+    SetPhotoApiKey(String),
+    SetImageGenEndpoint(String),
+    SetImageGenKey(String),
+    SetLookupUrl(String),
+    SetWebhookUrl(String),
+    SetGlobalLeaderboardUrl(String),
+    GlobalRanking(Result<Vec<leaderboard::Entry>, String>),
+    SetPlayerName(String),
+    ResultsSent(Result<(), String>),
+    Stickers(stickers::Msg),
+    SetAvatar(Option<avatar::Avatar>),
+    AvatarFile(web_sys::File),
+    AvatarFailed(String),
+    ToggleToddler,
+    ToggleListBoard,
+    TogglePictureWordPairs,
+    ToggleSpellingPractice,
+    SetAnswerPolicy(String),
+    TypeSpelling(String),
+    CheckSpelling,
+    SkipSpelling,
+    EditMidGame,
+    BackToGame,
+    ReplacePhoto {
+        id: Ulid,
+        file: web_sys::File,
+    },
+    PhotoReplaced {
+        id: Ulid,
+        result: Result<String, String>,
+    },
+    SetCardZoom(String),
+    SetColumnCount(String),
+    Pinch(pinch::Msg),
+    SetGamePairs(String),
+    ReplaySamePairs,
+    ToggleSpeedrun,
+    ToggleTracing,
+    SaveTraceJson,
+    SaveTraceCsv,
+    SpeedrunTick,
+    ClockTick,
+    RaceGuess(usize),
+    RaceReset,
+    Rematch,
+    CopyChallenge,
+    ChallengeCopied(Result<(), String>),
+    HoldExit,
+    ReleaseExit,
+    ExitHeld(u32),
+    SetSkin(String),
+
+    SetPhotoQuery(String),
+    SearchPhotos,
+    PhotosFound(Result<Vec<stock_photos::Photo>, String>),
+    AddSearchedPhoto(usize),
+    PhotoSearchFailed(String),
+
+    GeneratePicture(Ulid),
+    PictureGenerated {
+        id: Ulid,
+        result: Result<String, String>,
+    },
+
+    Suggest(Ulid),
+    Suggested {
+        id: Ulid,
+        result: Result<String, String>,
+    },
+    SuggestAll,
+    BatchSuggested {
+        id: Ulid,
+        result: Result<String, String>,
+    },
+    EditBatchSuggestion {
+        id: Ulid,
+        text: String,
+    },
+    ToggleBatchRow(Ulid),
+    ApplyBatchLookup,
+    CancelBatchLookup,
+
+    Generator(generators::Msg),
+    Library(library::Msg),
+    ChooseTemplate(wizard::Template),
+    SurpriseMe,
+    ExportPlayable,
+    ExportDeck,
+    CopyDeck,
+    ShareDeck,
+    DeckLinkCopied(Result<usize, String>),
+    DeckCopied(Result<(), String>),
+    PasteDeck,
+    DeckPasted(Result<String, String>),
+    ImportDeckFile(web_sys::File),
+    ImportCsvFile(web_sys::File),
+    ImportCsv(Vec<u8>),
+    DeckFileRead(Result<String, String>),
 
-    DragEnter,
+    DragEnter(drop_zone::DragSummary),
     DragOver,
     DragLeave,
-    Drop(FileList),
+    Drop(drop_zone::Dropped),
+    DropFailed(String),
+    ImageQueueUpdated,
+    ImageProcessed(String, Result<String, String>),
+    DismissImageErrors,
+    DismissNotification(u32),
+    Paste(Vec<web_sys::File>),
+    OpenCamera,
+    CameraStarted(Result<camera::Camera, String>),
+    ShowCamera,
+    CapturePhoto,
+    CloseCamera,
 }
 
-#[cfg_attr(feature = "cargo-clippy", allow(clippy::too_many_lines))]
-#[cfg_attr(
-    feature = "cargo-clippy",
-    allow(clippy::case_sensitive_file_extension_comparisons)
-)]
-// update, and make clippy allow too many lines since I don't feel like making this more readable
+// every message goes through here, so saving the game after a move can't be forgotten
 fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
-    match msg {
-        // create a new card based on NewCardType
-        Msg::NewCard(card_type) => {
-            let new_id = Ulid::new();
-
-            match card_type {
-                NewCardType::Empty => {
-                    let new_card = Card {
-                        id: new_id,
-                        photo: None,
-                        text: None,
-                    };
-                    model.words_list.entry(new_id).or_insert(new_card);
-                }
-                NewCardType::OnePhoto(content) => {
-                    let new_card = Card {
-                        id: new_id,
-                        photo: Some(content),
-                        text: None,
-                    };
-                    model.words_list.entry(new_id).or_insert(new_card);
-                }
-            }
-        }
-
-        // update a card with new text
-        Msg::UpdateCardText { id, text } => {
-            if !text.is_empty() {
-                if let Some(card) = model.words_list.get_mut(&id) {
-                    card.text = Some(text);
-                }
-            }
+    // a window following a shared game lets the controller play its clicks
+    if let Some(link) = model.link.as_ref().filter(|link| link.is_follower()) {
+        let forward = match msg {
+            Msg::GuessCard(index) => Some(sync::Message::Guess(index)),
+            Msg::ShowHideAll => Some(sync::Message::ShowHideAll),
+            Msg::StartGame => Some(sync::Message::PlayAgain),
+            _ => None,
+        };
+        if let Some(message) = forward {
+            link.send(&message);
+            return;
         }
-
-        // delete a card from the BTree
-        Msg::DeleteCard(id) => {
-            let _garbage = model.words_list.remove(&id);
+        // and "Create New" just stops following
+        if matches!(msg, Msg::ExitGame) {
+            update_model(Msg::LeaveSharedGame, model, orders);
+            return;
         }
+    }
 
-        // let me guess the card
-        Msg::GuessCard(index) => {
-            if model.needs_reset {
-                model.all_face_down();
-                return;
-            }
+    let changes_game = matches!(
+        msg,
+        Msg::GuessCard(_)
+            | Msg::ShowHideAll
+            | Msg::FlipBack(_)
+            | Msg::StartGame
+            | Msg::DeleteSkipped
+            | Msg::StartSkipping
+            | Msg::ReplaySamePairs
+            | Msg::Rematch
+            | Msg::NewSession
+            | Msg::ContinueAfterWin
+            | Msg::NextRound
+            | Msg::NewCampaign
+            | Msg::Library(library::Msg::PlaySelected)
+            | Msg::ExitGame
+            | Msg::EditDeck
+            | Msg::PickAnotherDeck
+            | Msg::ResetClick
+            | Msg::ResumeGame
+            | Msg::BackToGame
+    );
 
-            // do whatever based on whether there's a model.last or not
-            if let Some(last_guessed) = model.last {
-                // two IDs
-                let just_guessed = model.board[index].card.id;
-                if just_guessed == last_guessed {
-                    // the person guessed correctly!
-                    // set the cards to displayed and to matched = true
-                    for card in &mut model.board {
-                        if card.card.id == just_guessed || card.card.id == last_guessed {
-                            // card.displayed = CardState::FaceUp;
-                            card.matched = true;
-                        }
-                    }
-                    // set all to face down (to make toggle less messed up)
-                    // if card.matched == true, the card will be displayed regardless
-                    model.all_face_down();
-                    // set the last to none again, since it was a correct guess.
-                    model.last = None;
-                } else {
-                    // guessed incorrectly :(
-                    model.board[index].displayed = CardState::FaceUp;
-                    model.needs_reset = true;
-                }
-            } else {
-                // this will be the only flipped card, so set the last value to this one
-                model.last = Some(model.board[index].card.id);
-                // and flip the card so we can see it
-                model.board[index].displayed = CardState::FaceUp;
-            }
-        }
+    // messages that just keep the page, its timers and the saving going
+    let bookkeeping = matches!(
+        msg,
+        Msg::SaveDraft
+            | Msg::ObserveRows
+            | Msg::RowsVisible(_)
+            | Msg::PhotoLoaded(_)
+            | Msg::DragOver
+            | Msg::MoveCardOver(_)
+            | Msg::TrimPhoto(..)
+            | Msg::AnimationFrame(_)
+            | Msg::ClockTick
+            | Msg::SpeedrunTick
+            | Msg::SessionTick
+            | Msg::FlipBack(_)
+            | Msg::MissFlipBack(_)
+            | Msg::HideHighlight(_)
+            | Msg::HideDeleteNotice(_)
+            | Msg::DismissNotification(_)
+            | Msg::ExitHeld(_)
+            | Msg::VoicesChanged
+            | Msg::BoardDecoded(_)
+    );
+    let watch_rows = !bookkeeping;
+    // the cards can be changed in the editor, or while fixing them mid game
+    let editing = !model.game_started || model.editing_mid_game;
+    // anything done in the editor can be undone, except moving through the history itself
+    let undoable =
+        !model.game_started && !matches!(msg, Msg::Undo | Msg::Redo | Msg::DraftLoaded(_));
+    let typing = matches!(
+        msg,
+        Msg::UpdateCardText { .. } | Msg::UpdateCardTextB { .. }
+    );
+    // the messages that can change the cards or their order. only these are worth copying the
+    // list for (photos and all) to see what changed
+    let changes_cards = matches!(
+        msg,
+        Msg::NewCard(_)
+            | Msg::UpdateCardText { .. }
+            | Msg::UpdateCardTextB { .. }
+            | Msg::DeleteCard(_)
+            | Msg::DuplicateCard(_)
+            | Msg::MoveCard(_)
+            | Msg::DeleteSkipped
+            | Msg::ExitGame
+            | Msg::Undo
+            | Msg::Redo
+            | Msg::DraftLoaded(_)
+            | Msg::RestoreDraft
+            | Msg::AudioRecorded { .. }
+            | Msg::RemoveAudio(_)
+            | Msg::PhotoReplaced { .. }
+            | Msg::PictureGenerated { .. }
+            | Msg::Suggested { .. }
+            | Msg::ApplyBatchLookup
+            | Msg::Generator(_)
+            | Msg::Library(_)
+            | Msg::ImportCsv(_)
+            | Msg::DeckFileRead(_)
+            | Msg::DeckPasted(_)
+            | Msg::SurpriseMe
+            | Msg::Drop(_)
+            | Msg::CapturePhoto
+    );
+    // the ones that change a setting kept in storage
+    let changes_settings = matches!(
+        msg,
+        Msg::SetSkin(_)
+            | Msg::SetPhotoProvider(_)
+            | Msg::SetPhotoApiKey(_)
+            | Msg::SetImageGenEndpoint(_)
+            | Msg::SetImageGenKey(_)
+            | Msg::SetLookupUrl(_)
+            | Msg::SetWebhookUrl(_)
+            | Msg::SetGlobalLeaderboardUrl(_)
+            | Msg::SetPlayerName(_)
+    );
+    let words_before = (editing && changes_cards).then(|| model.words_list.clone());
+    let order_before = words_before.as_ref().map(|_| model.card_order.clone());
+    let deleting = matches!(msg, Msg::DeleteCard(_));
 
-        // show/hide all
-        Msg::ShowHideAll => {
-            // see if any are flipped already
-            let any_flipped = model
-                .board
-                .iter()
-                .any(|card| card.displayed == CardState::FaceUp);
+    let shown_before = shown_tiles(model);
+    // "Clear List" on the editor is an ExitGame too, that shouldn't touch a saved game
+    let was_playing = model.game_started;
 
-            // set an all_state. make all cards this next
-            let new_state: CardState = if any_flipped {
-                // one or more are already flipped, so face down
-                CardState::FaceDown
-            } else {
-                CardState::FaceUp
-            };
+    update_model(msg, model, orders);
 
-            // get a copy of the board vec
-            for card in &mut model.board {
-                card.displayed = new_state.clone();
-            }
+    animate_board(&shown_before, model, orders);
 
-            // clear last (if not cleared it will cause some weirdness)
-            model.last = None;
-        }
+    if changes_settings {
+        settings::save(model);
+    }
 
-        // start the game
-        Msg::StartGame => {
-            if model.words_list.len() < 2 {
-                return;
-            }
-            let mut new_board: Vec<PlayedCard> = vec![];
-            for card_pair in model.words_list.values() {
-                // skip the card if both photo and text are empty
-                if card_pair.text == None && card_pair.photo == None {
-                    continue;
-                }
+    // new rows in the editor need watching once they're on the page
+    if watch_rows && !model.game_started && model.row_observer.is_some() {
+        orders.after_next_render(|_| Msg::ObserveRows);
+    }
 
-                new_board.push(PlayedCard {
-                    displayed: CardState::FaceDown,
-                    matched: false,
-                    card: card_pair.clone(),
-                });
-                new_board.push(PlayedCard {
-                    displayed: CardState::FaceDown,
-                    matched: false,
-                    card: card_pair.clone(),
-                });
+    // whether the cards (or their order) changed, so there's something new to save
+    let mut cards_changed = false;
+    if let Some(words_before) = words_before {
+        // a new photo has to load again
+        for (id, card) in &words_before {
+            if model.words_list.get(id).map(|card| &card.photo) != Some(&card.photo) {
+                model.loaded_photos.remove(id);
             }
-
-            // now shuffle it to make it random
-            new_board.shuffle(&mut thread_rng());
-
-            // copy new_board to model.board
-            model.board = new_board;
-
-            // board is made, now set the model to show the game has started
-            model.game_started = true;
         }
-
-        // set the model to all the default values to start over
-        Msg::ExitGame => {
-            model.words_list = BTreeMap::new();
-            model.game_started = false;
-            model.board = vec![];
-            model.last = None;
-            model.needs_reset = false;
+        if undoable {
+            model
+                .history
+                .record(&words_before, &model.words_list, typing);
         }
-
-        // ResetClick will let me turn off the click listener and turn all cards FaceDown
-        Msg::ResetClick => {
-            // set all to face down
-            model.all_face_down();
+        let words_changed = words_before != model.words_list;
+        // undo wouldn't bring the deleted card back anymore
+        if !deleting && words_changed {
+            model.deleted_card = None;
         }
+        cards_changed = words_changed || order_before.as_ref() != Some(&model.card_order);
+    }
 
-        // ******
-        // the following is for dragging files
-        // from https://github.com/seed-rs/seed/blob/master/examples/drop_zone/src/lib.rs
-        // ******
-        Msg::DragEnter => model.drop_zone_active = true,
-
-        Msg::DragOver => (),
-
-        Msg::DragLeave => model.drop_zone_active = false,
-
-        Msg::Drop(file_list) => {
-            model.drop_zone_active = false;
-
-            let files = (0..file_list.length())
-                .filter_map(|index| {
-                    let file = file_list.get(index).expect("get file with given index");
-                    if file.name().to_lowercase().ends_with(".png")
-                        || file.name().to_lowercase().ends_with(".gif")
-                        || file.name().to_lowercase().ends_with(".jpg")
-                        || file.name().to_lowercase().ends_with(".jpeg")
-                    {
-                        Some(file)
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<_>>();
+    let following = model.link.as_ref().is_some_and(sync::Link::is_follower);
+    if changes_game && (was_playing || model.game_started) && !following {
+        saved_game::save(model);
+    }
 
-            for file in files {
-                // go through files, process them
-                orders.perform_cmd(async move {
-                    let result: JsValue = wasm_bindgen_futures::JsFuture::from(file.array_buffer())
-                        .await
-                        .expect("expected result from promise");
+    if changes_game {
+        if let Some(link) = model.link.as_ref().filter(|link| link.is_controller()) {
+            link.send(&shared_state(model));
+        }
+        // going back to the editor stops sharing
+        if !model.game_started && model.link.as_ref().is_some_and(sync::Link::is_controller) {
+            model.link = None;
+        }
+    }
 
-                    let array: Vec<u8> = js_sys::Uint8Array::new(&result).to_vec();
+    // keep the stored word list up to date while editing, including fixing cards mid game. the
+    // cards of a game played instead of the editor's (or of a challenge) go to the unsaved draft
+    if cards_changed && (!model.saves_words || model.set_aside.is_some()) {
+        model.unsaved_edits = true;
+    }
+    if cards_changed && !model.draft_pending {
+        model.draft_pending = true;
+        orders.perform_cmd(cmds::timeout(draft::SAVE_DELAY_MS, || Msg::SaveDraft));
+    }
 
-                    let pic: DynamicImage =
-                        image::load_from_memory(&array).expect("load pic from js array");
+    // keep the address bar on the page that's showing
+    let page = route::Page::of(model);
+    if page != model.page {
+        model.page = page;
+        page.push();
+    }
+}
 
-                    let format: ImageFormat = image::guess_format(&array).expect("guess format");
+// the copy of a card that goes on the board. for picture and word pairs, a card with both and
+// no partner tile of its own gets its word moved over to the partner tile
+fn dealt_card(card: &Card, picture_word_pairs: bool) -> Card {
+    let mut card = card.clone();
+    if picture_word_pairs && card.photo.is_some() && card.text_b.is_none() && card.sound.is_none() {
+        card.text_b = card.text.take();
+    }
+    card
+}
 
-                    let pic = pic.resize(THUMB_SIZE, THUMB_SIZE, image::imageops::Gaussian);
+// the game clock counts from now, ticking once a second to redraw
+fn start_clock(model: &mut Model, orders: &mut impl Orders<Msg>) {
+    model.clock_now = js_sys::Date::now();
+    model.clock =
+        Some(orders.stream_with_handle(streams::interval(clock::TICK_MS, || Msg::ClockTick)));
+}
 
-                    // from https://stackoverflow.com/questions/57457818/how-to-convert-dynamicimage-to-base64
-                    let mut blob_buf = vec![];
-                    let _garbage = pic.write_to(&mut blob_buf, format);
-                    let resized_pic_b64: String = base64::encode(&blob_buf);
+fn start_game(model: &mut Model, orders: &mut impl Orders<Msg>) {
+    model.confirm_skipped = false;
+    if model.words_list.len() < 2 {
+        return;
+    }
+    // a hot seat game from the editor asks who's playing first (a rematch keeps the players)
+    if model.mode == game::Mode::HotSeat && !model.game_started && !model.setting_up_players {
+        let players = model.player_names.len().max(hot_seat::MIN_PLAYERS);
+        model.player_names.resize(players, String::new());
+        model.setting_up_players = true;
+        return;
+    }
+    model.setting_up_players = false;
+    let mut new_board = model.new_board();
 
-                    // make a nice url here
-                    let format_string = match format {
-                        ImageFormat::Gif => "image/gif",
-                        ImageFormat::Png => "image/png",
-                        ImageFormat::Jpeg => "image/jpeg",
-                        _ => "image",
-                    };
+    // rounds mode only deals part of the deck, more each round
+    if model.mode == game::Mode::Rounds {
+        model.replay = None;
+        model.rematch_layout = None;
+        let campaign = match model.campaign.take() {
+            Some(mut campaign) if !campaign.is_finished() => {
+                if campaign.round_won() {
+                    campaign.next_round();
+                }
+                campaign
+            }
+            _ => {
+                let cards: BTreeSet<Ulid> = new_board.iter().map(|tile| tile.card.id).collect();
+                rounds::Campaign::new(cards.into_iter().collect())
+            }
+        };
+        let dealt = campaign.cards();
+        new_board.retain(|tile| dealt.contains(&tile.card.id));
+        model.campaign = Some(campaign);
+    } else {
+        model.campaign = None;
+        // the same cards as last time, or a fresh pick if there's a pair count
+        let dealt = model.replay.take().or_else(|| {
+            let pairs = game_pairs(&model.game_pairs)?;
+            let cards: BTreeMap<Ulid, Card> = new_board
+                .iter()
+                .map(|tile| (tile.card.id, tile.card.clone()))
+                .collect();
+            let cards: Vec<Card> = cards.into_values().collect();
+            let picked = library::sample(&cards, Some(pairs), &model.stats);
+            Some(picked.iter().map(|card| card.id).collect())
+        });
+        if let Some(dealt) = dealt {
+            new_board.retain(|tile| dealt.contains(&tile.card.id));
+        }
+    }
+    model.highlight = None;
+    model.focused_tile = None;
+    model.race = None;
+    model.speedrun = model.speedrun_enabled.then(speedrun::Speedrun::default);
+    model.trace = model.tracing.then(trace::Trace::start);
+    model.announcement = format!(
+        "new game, {} cards. use the arrow keys to move between them",
+        new_board.len()
+    );
+    model.speedrun_timer = None;
+    model.misses.clear();
+    model.tile_flips = vec![0; new_board.len()];
+    model.webhook_result = None;
+    model.stickers.clear_award();
+    model.game_started_at = js_sys::Date::now();
+    start_clock(model, orders);
+    model.new_best_time = false;
+    model.reviewing = false;
+    model.lives = (model.mode == game::Mode::Lives)
+        .then(|| lives::Lives::new(lives::max_lives(&model.lives_setting)));
+    model.countdown = (model.mode == game::Mode::Countdown)
+        .then(|| countdown::Countdown::start(countdown::seconds(&model.countdown_setting)));
 
-                    let nice_url_string =
-                        format!("data:{};base64,{}", format_string, resized_pic_b64);
+    // now shuffle it to make it random, with a seed that a challenge link can deal it again
+    // from. a rematch lays it out like last time instead, so it keeps last time's seed
+    if model.next_seed.is_none() {
+        model.challenge = None;
+    }
+    let seed = model.next_seed.take().unwrap_or_else(|| thread_rng().gen());
+    new_board.shuffle(&mut StdRng::seed_from_u64(seed));
+    match model.rematch_layout.take() {
+        Some(layout) => new_board.sort_by_key(|tile| {
+            layout
+                .iter()
+                .position(|spot| *spot == (tile.card.id, tile.half))
+        }),
+        None => model.board_seed = Some(seed),
+    }
+    model.finished_seconds = None;
+    model.challenge_status = None;
+    model.leaderboard_result = None;
+    model.global_ranking = None;
+    if model.mode == game::Mode::Race {
+        model.race = Some(race::Race::new(&new_board));
+    }
+    model.hot_seat =
+        (model.mode == game::Mode::HotSeat).then(|| hot_seat::HotSeat::new(&model.player_names));
 
-                    Msg::NewCard(NewCardType::OnePhoto(nice_url_string))
-                });
+    // the session clock starts with the first game, later ones just add to it
+    match &mut model.session {
+        Some(session) => session.next_game(&model.board),
+        None => {
+            if let Some(minutes) = session::minutes(&model.session_minutes) {
+                model.session = Some(session::Session::start(minutes));
+                model.session_timer =
+                    Some(orders.stream_with_handle(streams::interval(1000, || Msg::SessionTick)));
             }
         }
     }
-}
 
-// ------ ------
-//     View
-// ------ ------
+    // copy new_board to model.board
+    model.board = new_board;
+    // both tiles of a pair are the same card, count it once
+    let dealt: BTreeSet<Ulid> = model.board.iter().map(|tile| tile.card.id).collect();
+    model.stats.played(dealt);
 
-// from https://github.com/seed-rs/seed/blob/master/examples/drop_zone/src/lib.rs
-// set up drag events
-trait IntoDragEvent {
-    fn into_drag_event(self) -> DragEvent;
-}
+    // board is made, now set the model to show the game has started
+    model.game_started = true;
+    // a new game replaces whatever was saved
+    model.resumable = None;
 
-impl IntoDragEvent for Event {
-    fn into_drag_event(self) -> DragEvent {
-        self.dyn_into::<web_sys::DragEvent>()
-            .expect("cannot cast given event into DragEvent")
-    }
+    preload_board(model, orders);
 }
 
-macro_rules! stop_and_prevent {
-    { $event:expr } => {
-        {
-            $event.stop_propagation();
-            $event.prevent_default();
-        }
+// put a won game on its deck's leaderboard. only the whole saved deck played the usual way
+// counts, a few pairs from it or a rounds board would be a different game
+fn record_win(model: &mut Model, orders: &mut impl Orders<Msg>) {
+    let deck = model.library.name();
+    let dealt: BTreeSet<Ulid> = model.board.iter().map(|tile| tile.card.id).collect();
+    let whole_deck = model.library.decks.get(deck).is_some_and(|saved| {
+        saved.cards.len() == dealt.len() && saved.cards.iter().all(|card| dealt.contains(&card.id))
+    });
+    if !whole_deck || model.campaign.is_some() || model.race.is_some() || model.hot_seat.is_some() {
+        return;
+    }
+    let entry = leaderboard::Entry {
+        name: model.webhook.player.trim().to_string(),
+        moves: game::moves(&model.tile_flips),
+        seconds: model.finished_seconds.unwrap_or_default(),
+        date: js_sys::Date::now(),
     };
+    let deck = deck.to_string();
+    if model.global_leaderboard.is_configured() {
+        let (settings, deck, entry) = (
+            model.global_leaderboard.clone(),
+            deck.clone(),
+            entry.clone(),
+        );
+        orders.perform_cmd(async move {
+            Msg::GlobalRanking(global_leaderboard::submit(settings, deck, entry).await)
+        });
+    }
+    let place = model.leaderboards.record(&deck, entry);
+    model.leaderboard_result = Some((deck, place));
 }
 
-fn view(model: &Model) -> Vec<Node<Msg>> {
-    if model.game_started {
-        game_page(model)
-    } else {
-        new_words_page(model)
+// report a finished game to the teacher's webhook, if there is one
+fn send_results(model: &Model, orders: &mut impl Orders<Msg>) {
+    if !model.webhook.is_configured() {
+        return;
     }
+    let summary = webhook::Summary::new(
+        &model.webhook,
+        model.library.name(),
+        &model.board,
+        &model.misses,
+        &model.tile_flips,
+        (js_sys::Date::now() - model.game_started_at) / 1000.0,
+        model.speedrun.as_ref(),
+    );
+    let settings = model.webhook.clone();
+    orders.perform_cmd(async move { Msg::ResultsSent(webhook::send(settings, summary).await) });
 }
 
-// play the game page
-fn game_page(model: &Model) -> Vec<Node<Msg>> {
-    let all_cards: Vec<Node<Msg>> = model
+// parse the pairs per game setting, empty or 0 means the whole deck
+fn game_pairs(setting: &str) -> Option<usize> {
+    setting.trim().parse().ok().filter(|pairs| *pairs > 0)
+}
+
+// hide the board behind a loading message until every picture on it is ready to show
+fn preload_board(model: &mut Model, orders: &mut impl Orders<Msg>) {
+    let mut sources: BTreeSet<String> = model
         .board
         .iter()
-        .enumerate()
-        .map(|(index, played_card)| print_card(played_card, index))
+        .filter(|tile| tile.half == PairHalf::A)
+        .filter_map(|tile| tile.card.photo.clone())
         .collect();
+    sources.insert(model.skin.card_back());
+    sources.insert(ARROW_IMAGE.to_string());
 
-    // take cards and put them into divs for columns
-    let mut row: Vec<Node<Msg>> = vec![];
-    let mut all: Vec<Node<Msg>> = vec![];
-    for (index, card) in all_cards.iter().enumerate() {
-        row.push(card.clone());
+    model.preparing_board = true;
+    orders.perform_cmd(async move { Msg::BoardDecoded(preload::decode_all(sources).await) });
+}
 
-        // put the correct number of cards in a row
-        if (index + 1) % COLUMNS_NUMBER == 0 {
-            all.push(div![C!["columns"], &row]);
-            row.clear();
+// which tiles can be seen, to spot the ones a message turned over
+fn shown_tiles(model: &Model) -> Option<Vec<bool>> {
+    model.game_started.then(|| {
+        model
+            .board
+            .iter()
+            .map(|tile| tile.displayed == CardState::FaceUp || tile.matched)
+            .collect()
+    })
+}
+
+fn animate_board(
+    shown_before: &Option<Vec<bool>>,
+    model: &mut Model,
+    orders: &mut impl Orders<Msg>,
+) {
+    match (shown_before, shown_tiles(model)) {
+        (Some(before), Some(after)) if before.len() == after.len() => {
+            for (index, _) in before
+                .iter()
+                .zip(&after)
+                .enumerate()
+                .filter(|(_, (a, b))| a != b)
+            {
+                model.animations.flip(index);
+            }
+        }
+        // a different board, or none at all
+        (before, after) if before != &after => model.animations.clear(),
+        _ => {}
+    }
+
+    // the win screen waits for the last pair to finish turning over
+    let won = !model.board.is_empty() && model.board.iter().all(|tile| tile.matched);
+    if !won {
+        model.animations.stop_celebrating();
+    } else if !model.animations.is_flipping() {
+        model.animations.celebrate();
+    }
+
+    if model.animations.is_active() && !model.animations.running {
+        model.animations.running = true;
+        orders.after_next_render(|info| Msg::AnimationFrame(info.timestamp));
+    }
+}
+
+// arrow keys, home and end move around the board, enter or space turns the tile over. only
+// while a board is showing, and not when a button or text box has the keyboard
+fn board_key(event: &web_sys::KeyboardEvent) -> Option<Msg> {
+    if event.ctrl_key() || event.meta_key() || event.alt_key() {
+        return None;
+    }
+    let key = event.key();
+    if !matches!(
+        key.as_str(),
+        "ArrowUp" | "ArrowDown" | "ArrowLeft" | "ArrowRight" | "Home" | "End" | "Enter" | " "
+    ) {
+        return None;
+    }
+    let target = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+    let typing = target
+        .as_ref()
+        .and_then(|target| {
+            target
+                .closest("input, textarea, select, button:not([data-tile]), a, [contenteditable]")
+                .ok()?
+        })
+        .is_some();
+    if typing {
+        return None;
+    }
+    seed::document()
+        .query_selector("[data-keyboard-board]")
+        .ok()??;
+    let tile = target
+        .and_then(|target| target.closest("[data-keyboard-board] [data-tile]").ok()?)
+        .and_then(|tile| tile.get_attribute("data-tile")?.parse().ok());
+    // a tile's own button already clicks itself on enter and space
+    if tile.is_some() && matches!(key.as_str(), "Enter" | " ") {
+        return None;
+    }
+    Some(Msg::BoardKey(key, tile))
+}
+
+// move the browser's focus along with the arrow keys, so a screen reader follows
+fn focus_tile(index: usize) {
+    let selector = format!("[data-keyboard-board] [data-tile=\"{}\"]", index);
+    if let Some(tile) = seed::document()
+        .query_selector(&selector)
+        .ok()
+        .flatten()
+        .and_then(|tile| tile.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _garbage = tile.focus();
+    }
+}
+
+// ctrl+z undoes, ctrl+y or ctrl+shift+z redoes (cmd instead of ctrl on a mac)
+fn history_shortcut(event: &web_sys::KeyboardEvent) -> Option<Msg> {
+    if !(event.ctrl_key() || event.meta_key()) || event.alt_key() {
+        return None;
+    }
+    match event.key().to_lowercase().as_str() {
+        "z" if event.shift_key() => Some(Msg::Redo),
+        "z" => Some(Msg::Undo),
+        "y" => Some(Msg::Redo),
+        _ => None,
+    }
+}
+
+// what the controller tells the other windows after every move
+fn shared_state(model: &Model) -> sync::Message {
+    if model.game_started {
+        sync::Message::State(saved_game::SavedGame::from_model(model))
+    } else {
+        sync::Message::Ended
+    }
+}
+
+fn open_link(role: sync::Role, orders: &mut impl Orders<Msg>) -> Option<sync::Link> {
+    let msg_sender = orders.msg_sender();
+    sync::Link::open(role, move |message| msg_sender(Some(Msg::Synced(message))))
+        .map_err(|error| seed::log!(error))
+        .ok()
+}
+
+#[cfg_attr(feature = "cargo-clippy", allow(clippy::too_many_lines))]
+#[cfg_attr(
+    feature = "cargo-clippy",
+    allow(clippy::case_sensitive_file_extension_comparisons)
+)]
+// update, and make clippy allow too many lines since I don't feel like making this more readable
+fn update_model(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        // create a new card based on NewCardType
+        Msg::NewCard(card_type) => {
+            model.add_card(card_type);
+            // new cards go at the end, so show the page they're on
+            model.editor_page = (model.words_list.len() - 1) / EDITOR_PAGE_SIZE;
+        }
+
+        // update a card with new text
+        Msg::UpdateCardText { id, text } => {
+            if !text.is_empty() {
+                if let Some(card) = model.words_list.get_mut(&id) {
+                    card.text = Some(text);
+                }
+            }
+        }
+
+        // update the partner tile's text, empty means no partner text
+        Msg::UpdateCardTextB { id, text } => {
+            if let Some(card) = model.words_list.get_mut(&id) {
+                card.text_b = if text.is_empty() { None } else { Some(text) };
+            }
+        }
+
+        // dragging a row by its handle puts the card somewhere else in the list
+        Msg::StartMovingCard(id) => model.moving_card = Some(id),
+
+        Msg::MoveCardOver(id) => {
+            if model.moving_card.is_some() {
+                model.move_target = Some(id);
+            }
+        }
+
+        Msg::MoveCard(to) => {
+            if let Some(id) = model.moving_card.take() {
+                model.move_card(id, to);
+            }
+            model.move_target = None;
+        }
+
+        Msg::StopMovingCard => {
+            model.moving_card = None;
+            model.move_target = None;
+        }
+
+        Msg::DuplicateCard(id) => model.duplicate_card(id),
+
+        // delete a card from the BTree
+        Msg::DeleteCard(id) => {
+            if let Some(card) = model.words_list.remove(&id) {
+                let name = card
+                    .text
+                    .or(card.text_b)
+                    .map_or_else(|| "a card".to_string(), |text| format!("\"{}\"", text));
+                model.deleted_card = Some((id, name));
+                orders.perform_cmd(cmds::timeout(DELETE_NOTICE_MS, move || {
+                    Msg::HideDeleteNotice(id)
+                }));
+            }
+        }
+
+        Msg::HideDeleteNotice(id) => {
+            if model
+                .deleted_card
+                .as_ref()
+                .is_some_and(|(deleted, _)| *deleted == id)
+            {
+                model.deleted_card = None;
+            } else {
+                orders.skip();
+            }
+        }
+
+        // let me guess the card
+        Msg::GuessCard(index) => {
+            // no more guessing once the lives or the time are gone, or someone has won the race
+            if model.lives.as_ref().is_some_and(lives::Lives::is_out)
+                || model
+                    .countdown
+                    .as_ref()
+                    .is_some_and(countdown::Countdown::is_out)
+                || model.race.as_ref().is_some_and(race::Race::is_over)
+            {
+                return;
+            }
+            // nothing to click while a wrong pair is waiting to turn back by itself
+            if model.needs_reset && model.miss_reset.delay_ms().is_some() {
+                return;
+            }
+            // the tile that was showing, before guess moves on from it
+            let last = model.last;
+            let outcome = game::guess(model, index);
+            if let Some(trace) = &mut model.trace {
+                trace.guess(&outcome, index, &model.board);
+            }
+            // (screen readers pick it up from the page, the list board reads it out too)
+            if let Some(announcement) = list_board::announce(&outcome, index, &model.board) {
+                if model.list_board {
+                    speech::speak(&announcement, &model.speech);
+                }
+                model.announcement = announcement;
+            }
+            if outcome == game::Outcome::Missed {
+                let guessed = model.board[index].card.id;
+                for id in last.into_iter().chain([guessed]) {
+                    *model.misses.entry(id).or_default() += 1;
+                }
+                model.stats.missed(last.into_iter().chain([guessed]));
+            }
+            let won = outcome == game::Outcome::Matched && model.all_matched();
+            if let Some(race) = model.race.as_mut().filter(|_| won) {
+                race.winner = Some(race::Player::Left);
+            }
+            if let Some(hot_seat) = &mut model.hot_seat {
+                hot_seat.record(&outcome);
+            }
+            if let Some(lives) = &mut model.lives {
+                if outcome == game::Outcome::Missed {
+                    lives.lose_one();
+                }
+            }
+            if let Some(campaign) = &mut model.campaign {
+                campaign.record(&outcome);
+                if won {
+                    campaign.won_round();
+                }
+            }
+            // the word on the pair gets spelled, cards without one are skipped
+            if outcome == game::Outcome::Matched && model.spelling_practice {
+                model.spelling = model.board[index]
+                    .card
+                    .text
+                    .clone()
+                    .filter(|word| !word.trim().is_empty())
+                    .map(spelling::Spelling::new);
+            }
+            // (not in a race, it'd cover the other player's board, or on the list board, where
+            // it'd just get in the way of the keyboard)
+            if outcome == game::Outcome::Matched && model.race.is_none() && !model.list_board {
+                let id = model.board[index].card.id;
+                model.highlight = Some(id);
+                orders.perform_cmd(cmds::timeout(MATCH_HIGHLIGHT_MS, move || {
+                    Msg::HideHighlight(id)
+                }));
+            }
+            if outcome == game::Outcome::Missed {
+                if let Some(ms) = model.miss_reset.delay_ms() {
+                    let flip = model.flips;
+                    orders.perform_cmd(cmds::timeout(ms, move || Msg::MissFlipBack(flip)));
+                }
+            }
+            if outcome == game::Outcome::Flipped {
+                if let Some(ms) = model.difficulty.flip_back_ms() {
+                    let flip = model.flips;
+                    orders.perform_cmd(cmds::timeout(ms, move || Msg::FlipBack(flip)));
+                }
+            }
+            let out_of_lives = outcome == game::Outcome::Missed
+                && model.lives.as_ref().is_some_and(lives::Lives::is_out);
+            if let Some(speedrun) = &mut model.speedrun {
+                // the clock starts with the first tile, not when the board shows up
+                if outcome.flipped_tile()
+                    && model.speedrun_timer.is_none()
+                    && !(won || out_of_lives)
+                {
+                    speedrun.start();
+                    model.speedrun_timer = Some(
+                        orders.stream_with_handle(streams::interval(speedrun::REFRESH_MS, || {
+                            Msg::SpeedrunTick
+                        })),
+                    );
+                }
+                if outcome == game::Outcome::Matched {
+                    speedrun.split(model.board[index].card.id);
+                }
+                if won || out_of_lives {
+                    speedrun.finish();
+                    model.speedrun_timer = None;
+                }
+            }
+            if won || out_of_lives {
+                model.clock = None;
+            }
+            if won {
+                let seconds = model.speedrun.as_ref().map_or_else(
+                    || (js_sys::Date::now() - model.game_started_at) / 1000.0,
+                    |speedrun| speedrun.elapsed() / 1000.0,
+                );
+                model.finished_seconds = Some(seconds);
+                model.new_best_time =
+                    model.race.is_none() && model.best_times.record(&model.board, seconds);
+            }
+            if won {
+                record_win(model, orders);
+            }
+            if won || out_of_lives {
+                send_results(model, orders);
+            }
+            if won {
+                model.stickers.award();
+            }
+            if won && model.continuous {
+                orders.perform_cmd(cmds::timeout(CONTINUOUS_WIN_MS, || Msg::ContinueAfterWin));
+            }
+            if outcome.flipped_tile() {
+                // a resumed game starts counting from where it was picked up
+                model.tile_flips.resize(model.board.len(), 0);
+                model.tile_flips[index] += 1;
+                // sound tiles talk when they flip
+                let flipped = &model.board[index];
+                if let Some(clip) = &flipped.card.audio {
+                    audio::play(clip);
+                }
+                // (the list board already said it)
+                if flipped.half == PairHalf::B && !model.list_board {
+                    if let Some(sound) = &flipped.card.sound {
+                        speech::speak(sound, &model.speech);
+                    }
+                }
+                // and words are read out when the deck asks for it, unless a recording is
+                // already playing
+                let word = match (flipped.half, &flipped.card.sound) {
+                    (PairHalf::A, _) => flipped.card.text.as_deref(),
+                    (PairHalf::B, None) => flipped.card.text_b.as_deref(),
+                    (PairHalf::B, Some(_)) => None,
+                };
+                let reads =
+                    model.speech.read_words && !model.list_board && flipped.card.audio.is_none();
+                if let Some(word) = word.filter(|word| reads && !word.trim().is_empty()) {
+                    speech::speak(word, &model.speech);
+                }
+            }
+        }
+
+        // show/hide all
+        Msg::ShowHideAll => {
+            // see if any are flipped already
+            let any_flipped = model
+                .board
+                .iter()
+                .any(|card| card.displayed == CardState::FaceUp);
+
+            // set an all_state. make all cards this next
+            let new_state: CardState = if any_flipped {
+                // one or more are already flipped, so face down
+                CardState::FaceDown
+            } else {
+                CardState::FaceUp
+            };
+
+            // get a copy of the board vec
+            for card in &mut model.board {
+                card.displayed = new_state.clone();
+            }
+
+            // clear last (if not cleared it will cause some weirdness)
+            model.last = None;
+        }
+
+        // start the game
+        Msg::StartGame => {
+            // say which cards won't be in the game instead of quietly leaving them out
+            if !model.game_started && !model.skipped_cards().is_empty() {
+                model.confirm_skipped = true;
+                return;
+            }
+            start_game(model, orders);
+        }
+
+        Msg::FixSkipped => model.confirm_skipped = false,
+
+        Msg::DeleteSkipped => {
+            let skipped: Vec<Ulid> = model
+                .skipped_cards()
+                .iter()
+                .map(|(card, _)| card.id)
+                .collect();
+            for id in skipped {
+                let _garbage = model.words_list.remove(&id);
+            }
+            start_game(model, orders);
+        }
+
+        Msg::StartSkipping => start_game(model, orders),
+
+        // set the model to all the default values to start over
+        Msg::ExitGame => {
+            model.end_game();
+            model.set_cards(vec![]);
+            model.wizard_dismissed = false;
+        }
+
+        Msg::EditDeck => model.end_game(),
+
+        Msg::PickAnotherDeck => {
+            model.end_game();
+            model.library.choosing = !model.library.decks.is_empty();
+        }
+
+        // pick up where the last game left off
+        Msg::ResumeGame => {
+            if let Some(saved) = model.resumable.take() {
+                saved.restore(model);
+                model.game_started_at = js_sys::Date::now();
+                start_clock(model, orders);
+                // the saved board doesn't know its seed
+                model.board_seed = None;
+                preload_board(model, orders);
+            }
+        }
+
+        // the back and forward buttons
+        Msg::UrlChanged(subs::UrlChanged(url)) => {
+            let page = route::Page::from_url(&url).unwrap_or_default();
+            model.page = page;
+            match page {
+                route::Page::Play if !model.game_started => {
+                    orders.send_msg(if model.resumable.is_some() {
+                        Msg::ResumeGame
+                    } else {
+                        Msg::StartGame
+                    });
+                }
+                route::Page::Play => (),
+                route::Page::Edit | route::Page::Settings => {
+                    // leaving a game keeps it to come back to, it's saved after every move
+                    if model.game_started {
+                        model.end_game();
+                        model.resumable = saved_game::load();
+                    }
+                    model.settings_open = page == route::Page::Settings;
+                }
+            }
+        }
+
+        Msg::DiscardSavedGame => {
+            model.resumable = None;
+            saved_game::clear();
+        }
+
+        // ******
+        // playing across two windows
+        // ******
+        Msg::ShareGame => {
+            model.link = open_link(sync::Role::Controller, orders);
+            if let Some(link) = &model.link {
+                link.send(&shared_state(model));
+            }
+        }
+
+        Msg::JoinSharedGame => {
+            model.link = open_link(sync::Role::Follower, orders);
+            if let Some(link) = &model.link {
+                link.send(&sync::Message::Hello);
+            }
+        }
+
+        Msg::LeaveSharedGame => {
+            if let Some(link) = model.link.take() {
+                if link.is_controller() {
+                    link.send(&sync::Message::Ended);
+                } else {
+                    model.end_game();
+                }
+            }
+        }
+
+        Msg::Synced(message) => {
+            let Some(link) = &model.link else {
+                return;
+            };
+            match (link.role, message) {
+                (sync::Role::Controller, sync::Message::Hello) => {
+                    link.send(&shared_state(model));
+                    let text = "another window is following this game";
+                    model.notifications.add(Level::Info, text, orders);
+                }
+                (sync::Role::Controller, sync::Message::Guess(index))
+                    if index < model.board.len() =>
+                {
+                    orders.send_msg(Msg::GuessCard(index));
+                }
+                (sync::Role::Controller, sync::Message::ShowHideAll) => {
+                    orders.send_msg(Msg::ShowHideAll);
+                }
+                (sync::Role::Controller, sync::Message::PlayAgain) => {
+                    orders.send_msg(Msg::StartGame);
+                }
+                (sync::Role::Follower, sync::Message::State(game)) => game.restore(model),
+                (sync::Role::Follower, sync::Message::Ended) => {
+                    model.link = None;
+                    model.end_game();
+                    let text = "the other window left the game";
+                    model.notifications.add(Level::Info, text, orders);
+                }
+                _ => {}
+            }
+        }
+
+        Msg::ToggleContinuous => model.continuous = !model.continuous,
+
+        Msg::SetMode(name) => {
+            if let Some(mode) = game::Mode::ALL.iter().find(|mode| mode.name() == name) {
+                model.mode = *mode;
+            }
+        }
+
+        Msg::SetLives(lives) => model.lives_setting = lives,
+
+        Msg::SetCountdown(seconds) => model.countdown_setting = seconds,
+
+        Msg::SetHotSeatName(player, name) => {
+            if let Some(player_name) = model.player_names.get_mut(player) {
+                *player_name = name;
+            }
+        }
+
+        Msg::AddHotSeatPlayer => {
+            if model.player_names.len() < hot_seat::MAX_PLAYERS {
+                model.player_names.push(String::new());
+            }
+        }
+
+        Msg::RemoveHotSeatPlayer(player) => {
+            if model.player_names.len() > hot_seat::MIN_PLAYERS && player < model.player_names.len()
+            {
+                let _garbage = model.player_names.remove(player);
+            }
+        }
+
+        Msg::CancelPlayerSetup => {
+            model.setting_up_players = false;
+            // back to the editor's own list if saved decks were waiting to be played
+            if !model.game_started {
+                model.end_game();
+            }
+        }
+
+        Msg::SetDifficulty(name) => {
+            if let Some(difficulty) = game::Difficulty::ALL
+                .iter()
+                .find(|difficulty| difficulty.name() == name)
+            {
+                model.difficulty = *difficulty;
+            }
+        }
+
+        Msg::SetMissReset(name) => {
+            if let Some(miss_reset) = game::MissReset::ALL
+                .iter()
+                .find(|miss_reset| miss_reset.name() == name)
+            {
+                model.miss_reset = *miss_reset;
+            }
+        }
+
+        Msg::ToggleReview => model.reviewing = !model.reviewing,
+
+        // only hide the pair the timer was for, another match may have taken its place
+        Msg::HideHighlight(id) => {
+            if model.highlight == Some(id) {
+                model.highlight = None;
+            } else {
+                orders.skip();
+            }
+        }
+
+        // the lone face up tile has been showing long enough (BackToGame starts the wait over if
+        // it was up while fixing cards)
+        Msg::FlipBack(flip) => {
+            if model.editing_mid_game || !game::flip_back(model, flip) {
+                orders.skip();
+            } else if let Some(trace) = &mut model.trace {
+                trace.flip_back();
+            }
+        }
+
+        Msg::MissFlipBack(flip) => {
+            if model.editing_mid_game || !game::miss_flip_back(model, flip) {
+                orders.skip();
+                return;
+            }
+            if let Some(trace) = &mut model.trace {
+                trace.reset();
+            }
+            model.announcement = "cards turned back over".to_string();
+            if model.list_board {
+                speech::speak(&model.announcement, &model.speech);
+            }
+        }
+
+        // start_game moves on to the next round once this one is won
+        Msg::NextRound => start_game(model, orders),
+
+        Msg::NewCampaign => {
+            model.campaign = None;
+            start_game(model, orders);
+        }
+
+        Msg::ContinueAfterWin => {
+            // unless they've already left or started over
+            let session_over = model
+                .session
+                .as_ref()
+                .is_some_and(session::Session::is_over);
+            // or are going over the pairs
+            if model.continuous
+                && model.game_started
+                && model.all_matched()
+                && !session_over
+                && !model.reviewing
+            {
+                start_game(model, orders);
+            }
+        }
+
+        Msg::SetSessionMinutes(minutes) => model.session_minutes = minutes,
+
+        Msg::SessionTick => {
+            // the clocks stand still while cards are being fixed
+            if model.editing_mid_game {
+                orders.skip();
+                return;
+            }
+            if let Some(session) = &mut model.session {
+                session.tick();
+                if session.is_over() {
+                    model.session_timer = None;
+                }
+            }
+        }
+
+        // the next group at the station gets a fresh clock and board
+        Msg::NewSession => {
+            model.session = None;
+            start_game(model, orders);
+        }
+
+        Msg::AnimationFrame(timestamp) => {
+            model.animations.running = false;
+            if model.animations.tick(timestamp) {
+                model.animations.running = true;
+                orders.after_next_render(|info| Msg::AnimationFrame(info.timestamp));
+            }
+        }
+
+        Msg::SetEditorPage(page) => model.editor_page = page,
+
+        Msg::PhotoLoaded(id) => {
+            model.loaded_photos.insert(id);
+        }
+
+        Msg::ObserveRows => {
+            if let Some(observer) = &model.row_observer {
+                observer.observe_rows();
+            }
+            // nothing to redraw
+            orders.skip();
+        }
+
+        Msg::RowsVisible(rows) => {
+            for (id, visible) in rows {
+                if visible {
+                    model.visible_rows.insert(id);
+                } else {
+                    model.visible_rows.remove(&id);
+                }
+            }
+        }
+
+        Msg::BoardDecoded(images) => {
+            model.decoded_images = images;
+            model.preparing_board = false;
+        }
+
+        Msg::ToggleBoardPreview => model.board_preview_open = !model.board_preview_open,
+
+        Msg::Undo => {
+            model.deleted_card = None;
+            if !model.game_started {
+                model.history.undo(&mut model.words_list);
+            }
+        }
+
+        Msg::Redo => {
+            if !model.game_started {
+                model.history.redo(&mut model.words_list);
+            }
+        }
+
+        // (anything added while it loaded stays too)
+        Msg::DraftLoaded(draft) => {
+            if let Some(draft) = draft {
+                // (under a game that was picked up before the draft came back)
+                let (words_list, card_order) = model.editor_list();
+                add_to(words_list, card_order, draft.into_cards());
+            }
+            // only now, or an early save would write over it
+            model.saves_words = true;
+            // (after the list, to see if it's any different)
+            orders.perform_cmd(async { Msg::UnsavedDraftLoaded(draft::load_unsaved().await) });
+        }
+
+        // only worth asking about if it isn't the list that's already there
+        Msg::UnsavedDraftLoaded(draft) => match draft {
+            Some(draft) if !draft.cards().iter().eq(model.editor_cards()) => {
+                model.draft = Some(draft);
+            }
+            Some(_) => draft::clear_unsaved(),
+            None => (),
+        },
+
+        // bring back the cards that were being edited last time
+        Msg::RestoreDraft => {
+            if let Some(draft) = model.draft.take() {
+                model.set_cards(draft.into_cards());
+                draft::clear_unsaved();
+            }
+        }
+
+        Msg::DiscardDraft => {
+            model.draft = None;
+            draft::clear_unsaved();
+        }
+
+        Msg::DecksLoaded(decks) => {
+            if let Some(name) = model.library.loaded(decks) {
+                let text = format!("\"{}\" was added to your saved decks", name);
+                model.notifications.add(Level::Success, text, orders);
+            }
+        }
+
+        Msg::SaveDraft => {
+            model.draft_pending = false;
+            if model.saves_words {
+                draft::save(model);
+            }
+            if std::mem::take(&mut model.unsaved_edits) {
+                draft::save_unsaved(model.cards().cloned().collect());
+            }
+            // nothing to redraw
+            orders.skip();
+        }
+
+        // moving around the board from the keyboard, see board_key
+        Msg::BoardKey(key, tile) => {
+            let tiles = model.board.len();
+            if tiles == 0 {
+                orders.skip();
+                return;
+            }
+            let columns = model.columns();
+            let Some(focused) = tile.or(model.focused_tile).filter(|index| *index < tiles) else {
+                // the first key just shows where the focus is
+                model.focused_tile = Some(0);
+                orders.after_next_render(|_| focus_tile(0));
+                return;
+            };
+            model.focused_tile = Some(match key.as_str() {
+                "ArrowRight" => (focused + 1).min(tiles - 1),
+                "ArrowLeft" => focused.saturating_sub(1),
+                "ArrowDown" if focused + columns < tiles => focused + columns,
+                "ArrowUp" if focused >= columns => focused - columns,
+                "Home" => 0,
+                "End" => tiles - 1,
+                _ => focused,
+            });
+            if let Some(index) = model.focused_tile.filter(|index| *index != focused) {
+                orders.after_next_render(move |_| focus_tile(index));
+            }
+            if matches!(key.as_str(), "Enter" | " ") {
+                // the same as clicking it
+                let tile = &model.board[focused];
+                orders.send_msg(if tile.displayed == CardState::FaceUp || tile.matched {
+                    Msg::ResetClick
+                } else {
+                    Msg::GuessCard(focused)
+                });
+            }
+        }
+
+        // ResetClick will let me turn off the click listener and turn all cards FaceDown
+        Msg::ResetClick => {
+            // a wrong pair that turns back by itself can't be hurried along
+            if model.needs_reset && model.miss_reset.delay_ms().is_some() {
+                return;
+            }
+            // set all to face down
+            model.all_face_down();
+            if let Some(trace) = &mut model.trace {
+                trace.reset();
+            }
+            model.announcement = "cards turned back over".to_string();
+            if model.list_board {
+                speech::speak(&model.announcement, &model.speech);
+            }
+        }
+
+        // ******
+        // settings
+        // ******
+        Msg::ToggleSettings => model.settings_open = !model.settings_open,
+
+        Msg::VoicesChanged => model.voices = speech::available_voices(),
+
+        Msg::SetSpeechLang(lang) => {
+            let lang = lang.trim().to_string();
+            // forget the chosen voice if it can't read the new language
+            let voice_still_fits = model.voices.iter().any(|voice| {
+                Some(&voice.name) == model.speech.voice.as_ref() && voice.speaks(&lang)
+            });
+            if !voice_still_fits {
+                model.speech.voice = None;
+            }
+            model.speech.lang = lang;
+        }
+
+        Msg::SetSpeechVoice(name) => {
+            model.speech.voice = if name.is_empty() { None } else { Some(name) };
+        }
+
+        // read the first card (or something) so the teacher can hear the voice
+        Msg::PreviewVoice => {
+            let sample = model
+                .cards()
+                .find_map(|card| card.text.clone())
+                .unwrap_or_else(|| "1, 2, 3".to_string());
+            speech::speak(&sample, &model.speech);
+        }
+
+        Msg::Say(text) => speech::speak(&text, &model.speech),
+
+        Msg::ToggleReadWords => model.speech.read_words = !model.speech.read_words,
+
+        // ******
+        // sound clips on cards
+        // ******
+        Msg::PlayClip(clip) => audio::play(&clip),
+
+        Msg::RecordAudio(id) => {
+            model.card_errors.remove(&id);
+            let msg_sender = orders.msg_sender();
+            orders.perform_cmd(async move {
+                let result = audio::Recorder::start(move |result| {
+                    msg_sender(Some(Msg::AudioRecorded { id, result }));
+                })
+                .await;
+                Msg::RecordingStarted { id, result }
+            });
+        }
+
+        Msg::RecordingStarted { id, result } => match result {
+            // a second click while the browser was still asking for the microphone, the first
+            // recording carries on
+            Ok(recorder) if model.recording.is_some() => recorder.cancel(),
+            Ok(recorder) => model.recording = Some((id, recorder)),
+            Err(error) => {
+                model.card_errors.insert(id, error);
+            }
+        },
+
+        // the recorder stays around until the clip comes back
+        Msg::StopRecording => {
+            if let Some((_, recorder)) = &model.recording {
+                recorder.stop();
+            }
+        }
+
+        // (picked sound files come back this way too)
+        Msg::AudioRecorded { id, result } => {
+            if model
+                .recording
+                .as_ref()
+                .is_some_and(|(recording, _)| *recording == id)
+            {
+                model.recording = None;
+            }
+            set_audio(model, id, result);
+        }
+
+        Msg::AttachAudio { id, file } => {
+            model.card_errors.remove(&id);
+            orders.perform_cmd(async move {
+                Msg::AudioRecorded {
+                    id,
+                    result: audio::from_file(file).await,
+                }
+            });
+        }
+
+        Msg::RemoveAudio(id) => {
+            if let Some(card) = model.words_list.get_mut(&id) {
+                card.audio = None;
+            }
+        }
+
+        // a dropped sound file becomes a card, named after the file
+        Msg::AudioFileDropped(file) => {
+            let name = file.name();
+            let text = name
+                .rsplit_once('.')
+                .map_or(name.as_str(), |(stem, _)| stem)
+                .to_string();
+            orders.perform_cmd(async move {
+                match audio::from_file(file).await {
+                    Ok(clip) => Msg::NewCard(NewCardType::AudioWithText(clip, text)),
+                    Err(error) => Msg::DropFailed(error),
+                }
+            });
+        }
+
+        Msg::SetPhotoProvider(name) => {
+            if let Some(provider) = stock_photos::Provider::from_name(&name) {
+                model.stock_photos.provider = provider;
+            }
+        }
+
+        Msg::SetPhotoApiKey(key) => model.stock_photos.api_key = key.trim().to_string(),
+
+        Msg::SetImageGenEndpoint(endpoint) => {
+            model.image_gen.endpoint = endpoint.trim().to_string()
+        }
+
+        Msg::SetImageGenKey(key) => model.image_gen.api_key = key.trim().to_string(),
+
+        Msg::SetLookupUrl(url) => model.lookup.url_template = url.trim().to_string(),
+
+        Msg::SetWebhookUrl(url) => model.webhook.url = url.trim().to_string(),
+
+        Msg::SetGlobalLeaderboardUrl(url) => {
+            model.global_leaderboard.url = url.trim().to_string();
+        }
+
+        Msg::GlobalRanking(ranking) => model.global_ranking = Some(ranking),
+
+        Msg::SetPlayerName(player) => model.webhook.player = player,
+
+        Msg::ResultsSent(result) => model.webhook_result = Some(result),
+
+        Msg::Stickers(msg) => stickers::update(msg, &mut model.stickers),
+
+        Msg::SetAvatar(avatar) => {
+            model.avatar = avatar;
+            model.avatar_error = None;
+        }
+
+        Msg::AvatarFile(file) => {
+            orders.perform_cmd(async move {
+                match avatar::from_file(file).await {
+                    Ok(avatar) => Msg::SetAvatar(Some(avatar)),
+                    Err(error) => Msg::AvatarFailed(error),
+                }
+            });
+        }
+
+        Msg::AvatarFailed(error) => model.avatar_error = Some(error),
+
+        // ******
+        // fixing cards without leaving the game
+        // ******
+        Msg::EditMidGame => {
+            model.editing_mid_game = true;
+            model.paused_at = js_sys::Date::now();
+            if let Some(speedrun) = &mut model.speedrun {
+                speedrun.pause();
+            }
+        }
+
+        Msg::BackToGame => {
+            model.editing_mid_game = false;
+            mid_game_edit::refresh(
+                &mut model.board,
+                &model.words_list,
+                model.picture_word_pairs,
+            );
+            if let Some(speedrun) = &mut model.speedrun {
+                speedrun.resume();
+            }
+            // the time spent fixing cards doesn't count against any of the clocks
+            let paused = js_sys::Date::now() - model.paused_at;
+            model.game_started_at += paused;
+            if let Some(countdown) = &mut model.countdown {
+                countdown.pause_for(paused);
+            }
+            if let Some(session) = &mut model.session {
+                session.pause_for(paused);
+            }
+            // and tiles waiting to turn back over get their whole wait again
+            let flip = model.flips;
+            if model.needs_reset {
+                if let Some(ms) = model.miss_reset.delay_ms() {
+                    orders.perform_cmd(cmds::timeout(ms, move || Msg::MissFlipBack(flip)));
+                }
+            } else if model.last.is_some() {
+                if let Some(ms) = model.difficulty.flip_back_ms() {
+                    orders.perform_cmd(cmds::timeout(ms, move || Msg::FlipBack(flip)));
+                }
+            }
+        }
+
+        Msg::ReplacePhoto { id, file } => {
+            model.card_errors.remove(&id);
+            orders.perform_cmd(async move {
+                Msg::PhotoReplaced {
+                    id,
+                    result: thumbnail::from_file(file).await,
+                }
+            });
+        }
+
+        Msg::PhotoReplaced { id, result } => match result {
+            Ok(photo) => {
+                if let Some(card) = model.words_list.get_mut(&id) {
+                    card.preview = thumbnail::preview_url(&photo);
+                    card.photo = Some(photo);
+                    // the new picture needs to load before it shows on the board
+                    model.loaded_photos.remove(&id);
+                }
+            }
+            Err(error) => {
+                model.card_errors.insert(id, error);
+            }
+        },
+
+        Msg::EditPhoto(id) => {
+            if let Some(photo) = model
+                .words_list
+                .get(&id)
+                .and_then(|card| card.photo.clone())
+            {
+                model.photo_edit = Some(photo_edit::PhotoEdit::new(id, photo));
+            }
+        }
+
+        Msg::TurnPhoto(clockwise) => {
+            if let Some(edit) = &mut model.photo_edit {
+                edit.turn(clockwise);
+            }
+        }
+
+        Msg::TrimPhoto(edge, percent) => {
+            if let Some(edit) = &mut model.photo_edit {
+                edit.set_trim(edge, &percent);
+            }
+        }
+
+        // the fixed picture comes back like a newly picked one
+        Msg::ApplyPhotoEdit => {
+            if let Some(edit) = model.photo_edit.take() {
+                let id = edit.id;
+                model.card_errors.remove(&id);
+                orders.perform_cmd(async move {
+                    Msg::PhotoReplaced {
+                        id,
+                        result: edit.apply().await,
+                    }
+                });
+            }
+        }
+
+        Msg::CancelPhotoEdit => model.photo_edit = None,
+
+        Msg::ToggleToddler => model.toddler = !model.toddler,
+
+        Msg::ToggleListBoard => model.list_board = !model.list_board,
+        Msg::TogglePictureWordPairs => model.picture_word_pairs = !model.picture_word_pairs,
+
+        // ******
+        // spelling practice
+        // ******
+        Msg::ToggleSpellingPractice => model.spelling_practice = !model.spelling_practice,
+
+        Msg::SetAnswerPolicy(name) => {
+            if let Some(policy) = game::AnswerPolicy::ALL
+                .iter()
+                .find(|policy| policy.name() == name)
+            {
+                model.answer_policy = *policy;
+            }
+        }
+
+        Msg::TypeSpelling(typed) => {
+            if let Some(spelling) = &mut model.spelling {
+                spelling.type_in(typed);
+            }
+        }
+
+        Msg::CheckSpelling => {
+            if let Some(spelling) = &mut model.spelling {
+                spelling.check(model.answer_policy);
+            }
+        }
+
+        Msg::SkipSpelling => model.spelling = None,
+        Msg::Pinch(msg) => pinch::update(msg, &mut model.pinch),
+        Msg::SetColumnCount(columns) => {
+            model.column_count = columns
+                .parse()
+                .ok()
+                .filter(|columns| COLUMN_CHOICES.contains(columns));
+        }
+        Msg::SetCardZoom(zoom) => {
+            let (min, max) = CARD_ZOOM_RANGE;
+            model.card_zoom = zoom.parse::<usize>().ok().map(|zoom| zoom.clamp(min, max));
+        }
+
+        Msg::SetGamePairs(pairs) => model.game_pairs = pairs,
+
+        Msg::ToggleSpeedrun => model.speedrun_enabled = !model.speedrun_enabled,
+
+        Msg::ToggleTracing => model.tracing = !model.tracing,
+
+        Msg::SaveTraceJson => {
+            if let Some(trace) = &model.trace {
+                trace.save_json();
+            }
+        }
+
+        Msg::SaveTraceCsv => {
+            if let Some(trace) = &model.trace {
+                trace.save_csv();
+            }
+        }
+
+        Msg::ClockTick => {
+            if model.editing_mid_game {
+                orders.skip();
+                return;
+            }
+            model.clock_now = js_sys::Date::now();
+            let now = model.clock_now;
+            if model
+                .countdown
+                .as_ref()
+                .is_some_and(|countdown| countdown.is_due(now))
+            {
+                // the teacher hears about it before the board is turned back over
+                send_results(model, orders);
+                if let Some(countdown) = &mut model.countdown {
+                    countdown.run_out(&mut model.board);
+                }
+                model.last = None;
+                model.needs_reset = false;
+                model.highlight = None;
+                model.clock = None;
+                if let Some(speedrun) = &mut model.speedrun {
+                    speedrun.finish();
+                    model.speedrun_timer = None;
+                }
+            }
+        }
+
+        Msg::SpeedrunTick => {
+            if let Some(speedrun) = &mut model.speedrun {
+                speedrun.tick();
+            }
+        }
+
+        // a click on the second board in race mode
+        Msg::RaceGuess(index) => {
+            if model.race.as_ref().is_none_or(race::Race::is_over) {
+                return;
+            }
+            race::guess(model, index);
+        }
+
+        Msg::RaceReset => {
+            if let Some(race) = &mut model.race {
+                race.reset();
+            }
+        }
+
+        Msg::ReplaySamePairs => {
+            model.replay = Some(model.board.iter().map(|tile| tile.card.id).collect());
+            start_game(model, orders);
+        }
+
+        Msg::CopyChallenge => {
+            model.challenge_status = None;
+            match challenge::Challenge::from_model(model) {
+                Some(challenge) => {
+                    let link = challenge.link();
+                    orders.perform_cmd(async move {
+                        Msg::ChallengeCopied(clipboard::write_text(link).await)
+                    });
+                }
+                None => {
+                    model.challenge_status =
+                        Some(Err("this board can't be dealt again".to_string()));
+                }
+            }
+        }
+
+        Msg::ChallengeCopied(result) => model.challenge_status = Some(result),
+
+        // the board is the layout, so this works for a resumed game too
+        Msg::Rematch => {
+            model.replay = Some(model.board.iter().map(|tile| tile.card.id).collect());
+            model.rematch_layout = Some(
+                model
+                    .board
+                    .iter()
+                    .map(|tile| (tile.card.id, tile.half))
+                    .collect(),
+            );
+            start_game(model, orders);
+        }
+
+        Msg::HoldExit => {
+            model.holding_exit = true;
+            model.exit_holds += 1;
+            let hold = model.exit_holds;
+            orders.perform_cmd(cmds::timeout(toddler::HOLD_MS, move || Msg::ExitHeld(hold)));
+        }
+
+        Msg::ReleaseExit => model.holding_exit = false,
+
+        // still held down since the press the timer was for
+        Msg::ExitHeld(hold) => {
+            if model.holding_exit && hold == model.exit_holds {
+                model.holding_exit = false;
+                // (back to the editor with the deck, like any game's end)
+                orders.send_msg(Msg::EditDeck);
+            }
+        }
+
+        Msg::SetSkin(name) => {
+            if let Some(skin) = skins::Skin::from_name(&name) {
+                model.skin = skin;
+            }
+        }
+
+        // ******
+        // stock photo search
+        // ******
+        Msg::SetPhotoQuery(query) => model.photo_search.query = query,
+
+        Msg::SearchPhotos => {
+            if model.photo_search.query.trim().is_empty() || model.photo_search.searching {
+                return;
+            }
+            model.photo_search.searching = true;
+            model.photo_search.error = None;
+            let settings = model.stock_photos.clone();
+            let query = model.photo_search.query.clone();
+            orders.perform_cmd(async move {
+                Msg::PhotosFound(stock_photos::search(settings, query).await)
+            });
+        }
+
+        Msg::PhotosFound(result) => {
+            model.photo_search.searching = false;
+            match result {
+                Ok(photos) => {
+                    if photos.is_empty() {
+                        model.photo_search.error = Some("no pictures found".to_string());
+                    }
+                    model.photo_search.results = photos;
+                }
+                Err(error) => model.photo_search.error = Some(error),
+            }
+        }
+
+        Msg::AddSearchedPhoto(index) => {
+            if let Some(photo) = model.photo_search.results.get(index).cloned() {
+                orders.perform_cmd(stock_photos::download(photo));
+            }
+        }
+
+        Msg::PhotoSearchFailed(error) => model.photo_search.error = Some(error),
+
+        // ******
+        // generated pictures
+        // ******
+        Msg::GeneratePicture(id) => {
+            let text = match model.words_list.get(&id).and_then(|card| card.text.clone()) {
+                Some(text) => text,
+                None => return,
+            };
+            if !model.image_gen.is_configured() || !model.generating.insert(id) {
+                return;
+            }
+            model.card_errors.remove(&id);
+            let settings = model.image_gen.clone();
+            orders.perform_cmd(async move {
+                Msg::PictureGenerated {
+                    id,
+                    result: image_gen::generate(settings, text).await,
+                }
+            });
+        }
+
+        Msg::PictureGenerated { id, result } => {
+            model.generating.remove(&id);
+            match result {
+                Ok(photo) => {
+                    // the card might have been deleted while waiting
+                    if let Some(card) = model.words_list.get_mut(&id) {
+                        card.preview = thumbnail::preview_url(&photo);
+                        card.photo = Some(photo);
+                    }
+                }
+                Err(error) => {
+                    model.card_errors.insert(id, error);
+                }
+            }
+        }
+
+        // ******
+        // dictionary/translation suggestions
+        // ******
+        Msg::Suggest(id) => {
+            let word = match model.words_list.get(&id).and_then(|card| card.text.clone()) {
+                Some(word) => word,
+                None => return,
+            };
+            if !model.lookup.is_configured() || !model.suggesting.insert(id) {
+                return;
+            }
+            model.card_errors.remove(&id);
+            let settings = model.lookup.clone();
+            let lang = model.speech.lang.clone();
+            orders.perform_cmd(async move {
+                Msg::Suggested {
+                    id,
+                    result: lookup::suggest(settings, lang, word).await,
+                }
+            });
+        }
+
+        Msg::Suggested { id, result } => {
+            model.suggesting.remove(&id);
+            match result {
+                Ok(text) => {
+                    if let Some(card) = model.words_list.get_mut(&id) {
+                        card.text_b = Some(text);
+                    }
+                }
+                Err(error) => {
+                    model.card_errors.insert(id, error);
+                }
+            }
+        }
+
+        // look up every card with text, then wait for the teacher to review
+        Msg::SuggestAll => {
+            if !model.lookup.is_configured() || model.batch_lookup.is_some() {
+                return;
+            }
+            let mut batch = lookup::BatchLookup::default();
+            for card in model.cards() {
+                let word = match &card.text {
+                    Some(word) => word.clone(),
+                    None => continue,
+                };
+                batch.rows.insert(
+                    card.id,
+                    lookup::BatchRow {
+                        word: word.clone(),
+                        current: card.text_b.clone(),
+                        result: None,
+                        // don't replace matching text someone already typed unless asked
+                        keep: card.text_b.is_none(),
+                    },
+                );
+
+                let id = card.id;
+                let settings = model.lookup.clone();
+                let lang = model.speech.lang.clone();
+                orders.perform_cmd(async move {
+                    Msg::BatchSuggested {
+                        id,
+                        result: lookup::suggest(settings, lang, word).await,
+                    }
+                });
+            }
+            if !batch.rows.is_empty() {
+                model.batch_lookup = Some(batch);
+            }
+        }
+
+        Msg::BatchSuggested { id, result } => {
+            if let Some(row) = model
+                .batch_lookup
+                .as_mut()
+                .and_then(|batch| batch.rows.get_mut(&id))
+            {
+                if result.is_err() {
+                    row.keep = false;
+                }
+                row.result = Some(result);
+            }
+        }
+
+        Msg::EditBatchSuggestion { id, text } => {
+            if let Some(row) = model
+                .batch_lookup
+                .as_mut()
+                .and_then(|batch| batch.rows.get_mut(&id))
+            {
+                row.result = Some(Ok(text));
+            }
+        }
+
+        Msg::ToggleBatchRow(id) => {
+            if let Some(row) = model
+                .batch_lookup
+                .as_mut()
+                .and_then(|batch| batch.rows.get_mut(&id))
+            {
+                row.keep = !row.keep;
+            }
+        }
+
+        Msg::ApplyBatchLookup => {
+            if let Some(batch) = model.batch_lookup.take() {
+                for (id, text) in batch.accepted() {
+                    if let Some(card) = model.words_list.get_mut(&id) {
+                        card.text_b = Some(text.to_string());
+                    }
+                }
+            }
+        }
+
+        Msg::CancelBatchLookup => model.batch_lookup = None,
+
+        // ******
+        // deck generators
+        // ******
+        Msg::Generator(generators::Msg::Generate) => {
+            for card_type in generators::generate(&model.generators) {
+                model.add_card(card_type);
+            }
+        }
+
+        Msg::Generator(msg) => generators::update(msg, &mut model.generators),
+
+        Msg::Library(library::Msg::Save) => {
+            let name = model.library.name().to_string();
+            model
+                .library
+                .insert(name.clone(), deck::Deck::from_model(model));
+            let text = format!("saved \"{}\"", name);
+            model.notifications.add(Level::Success, text, orders);
+        }
+
+        Msg::Library(library::Msg::Delete(name)) => {
+            model.leaderboards.forget(&name);
+            library::update(library::Msg::Delete(name), &mut model.library);
+        }
+
+        Msg::Library(library::Msg::Load(name)) => {
+            if let Some(deck) = model.library.decks.get(&name).cloned() {
+                model.speech = deck.speech();
+                model.answer_policy = deck.answer_policy;
+                model.set_cards(deck.cards);
+                model.editor_page = 0;
+                model.library.set_name(name);
+                model.library.choosing = false;
+            }
+        }
+
+        // an empty list to fill in and save under a new name
+        Msg::Library(library::Msg::NewDeck) => {
+            model.set_cards(vec![]);
+            model.editor_page = 0;
+            model.library.set_name(String::new());
+            model.library.choosing = false;
+        }
+
+        // one board from several decks. if there aren't enough cards for a game the
+        // editor gets its own deck back
+        Msg::Library(library::Msg::PlaySelected) => {
+            let combined = model.library.combined(&model.stats);
+            let order = combined.iter().map(|card| card.id).collect();
+            let words = combined.into_iter().map(|card| (card.id, card)).collect();
+            model.play_other_cards(words, order);
+            start_game(model, orders);
+            // (hot seat asks who's playing first, the decks wait for that)
+            if !model.game_started && !model.setting_up_players {
+                model.end_game();
+            }
+        }
+
+        Msg::Library(msg) => library::update(msg, &mut model.library),
+
+        Msg::ChooseTemplate(template) => wizard::apply(template, model),
+
+        // save a single html file that plays this deck when opened
+        Msg::ExportPlayable => {
+            let html = standalone::html(&deck::Deck::from_model(model));
+            download::save_file("matching-game.html", "text/html", &html);
+        }
+
+        // the deck as a json file, photos and all, to keep or to open somewhere else
+        Msg::ExportDeck => {
+            let json = deck::Deck::from_model(model).to_json();
+            download::save_file(
+                &deck::file_name(model.library.name()),
+                "application/json",
+                &json,
+            );
+        }
+
+        // put the deck json on the clipboard, to paste into a chat or email
+        Msg::CopyDeck => {
+            let json = deck::Deck::from_model(model).to_json();
+            orders.perform_cmd(async move { Msg::DeckCopied(clipboard::write_text(json).await) });
+        }
+
+        Msg::DeckCopied(result) => notify_result(
+            model,
+            result
+                .map(|()| "deck copied, paste it anywhere".to_string())
+                .map_err(|error| format!("couldn't copy: {}", error)),
+            orders,
+        ),
+
+        Msg::ShareDeck => {
+            let shared = share::Shared::new(model.library.name(), deck::Deck::from_model(model));
+            let (link, cards) = (shared.link(), shared.deck.cards.len());
+            orders.perform_cmd(async move {
+                Msg::DeckLinkCopied(clipboard::write_text(link).await.map(|()| cards))
+            });
+        }
+
+        Msg::DeckLinkCopied(result) => notify_result(
+            model,
+            result
+                .map(|cards| {
+                    format!(
+                        "link copied, it opens these {} cards (without their pictures or sounds)",
+                        cards
+                    )
+                })
+                .map_err(|error| format!("couldn't copy the link: {}", error)),
+            orders,
+        ),
+
+        Msg::PasteDeck => {
+            orders.perform_cmd(async { Msg::DeckPasted(clipboard::read_text().await) });
+        }
+
+        // add the pasted deck's cards to this one
+        Msg::DeckPasted(result) => {
+            let result = result
+                .and_then(|json| add_deck(model, &json))
+                .map(|count| format!("added {} cards from the clipboard", count))
+                .map_err(|error| format!("couldn't import: {}", error));
+            notify_result(model, result, orders);
+        }
+
+        // an exported deck file, picked or dropped. its cards join the list
+        Msg::ImportDeckFile(file) => {
+            orders.perform_cmd(async move { Msg::DeckFileRead(drop_zone::read_text(file).await) });
+        }
+
+        // a spreadsheet saved as csv, a text card for each row
+        Msg::ImportCsvFile(file) => {
+            orders.perform_cmd(async move {
+                match drop_zone::read_bytes(file).await {
+                    Ok(bytes) => Msg::ImportCsv(bytes),
+                    Err(error) => Msg::DropFailed(error),
+                }
+            });
+        }
+
+        Msg::ImportCsv(bytes) => {
+            let rows = csv::cards(&bytes);
+            if rows.is_empty() {
+                model.notifications.add(
+                    Level::Warning,
+                    "there weren't any cards in that csv file",
+                    orders,
+                );
+            }
+            for (text, text_b) in rows {
+                model.add_card(match text_b {
+                    Some(text_b) => NewCardType::TextPair(text, text_b),
+                    None => NewCardType::Text(text),
+                });
+            }
+            model.editor_page = model.words_list.len().saturating_sub(1) / EDITOR_PAGE_SIZE;
+        }
+
+        Msg::DeckFileRead(result) => {
+            match result.and_then(|json| add_deck(model, &json)) {
+                Ok(count) => {
+                    let text = format!("added {} cards from the deck", count);
+                    model.notifications.add(Level::Success, text, orders);
+                }
+                Err(error) => {
+                    let text = format!("couldn't import: {}", error);
+                    model.notifications.add(Level::Danger, text, orders);
+                }
+            }
+            model.editor_page = model.words_list.len().saturating_sub(1) / EDITOR_PAGE_SIZE;
+        }
+
+        // a random emoji deck, straight into a game
+        Msg::SurpriseMe => {
+            if !model.words_list.is_empty() {
+                return;
+            }
+            for card_type in generators::surprise() {
+                model.add_card(card_type);
+            }
+            orders.send_msg(Msg::StartGame);
+        }
+
+        // ******
+        // the following is for dragging files
+        // from https://github.com/seed-rs/seed/blob/master/examples/drop_zone/src/lib.rs
+        // ******
+        // (a row being moved around the editor isn't a drop)
+        Msg::DragEnter(_) if model.moving_card.is_some() => (),
+
+        Msg::DragEnter(summary) => {
+            model.drop_zone_active = true;
+            model.drag_summary = summary;
+        }
+
+        Msg::DragOver => (),
+
+        Msg::DragLeave => {
+            model.drop_zone_active = false;
+            model.drag_summary = drop_zone::DragSummary::default();
+        }
+
+        Msg::Drop(drop_zone::Dropped::Text(text)) => {
+            model.drop_zone_active = false;
+            model.drag_summary = drop_zone::DragSummary::default();
+
+            for (word, translation) in drop_zone::words(&text) {
+                model.add_card(match translation {
+                    Some(translation) => NewCardType::TextPair(word, translation),
+                    None => NewCardType::Text(word),
+                });
+            }
+            model.editor_page = model.words_list.len().saturating_sub(1) / EDITOR_PAGE_SIZE;
+        }
+
+        Msg::Drop(drop_zone::Dropped::ImageUrl(url)) => {
+            model.drop_zone_active = false;
+            model.drag_summary = drop_zone::DragSummary::default();
+
+            orders.perform_cmd(async move {
+                match drop_zone::fetch_image(url).await {
+                    Ok(photo) => Msg::NewCard(NewCardType::OnePhoto(photo)),
+                    Err(error) => Msg::DropFailed(error),
+                }
+            });
+        }
+
+        Msg::DropFailed(error) => model.notifications.add(Level::Danger, error, orders),
+
+        Msg::Drop(drop_zone::Dropped::Files(file_list)) => {
+            model.drop_zone_active = false;
+            model.drag_summary = drop_zone::DragSummary::default();
+
+            add_files(
+                model,
+                (0..file_list.length()).filter_map(|index| file_list.get(index)),
+                orders,
+            );
+        }
+
+        // start on as many waiting pictures as there's room for
+        Msg::ImageQueueUpdated => {
+            for file in model.image_queue.start() {
+                orders.perform_cmd(async move {
+                    Msg::ImageProcessed(file.name(), thumbnail::from_file(file).await)
+                });
+            }
+        }
+
+        // a file that didn't work is listed, and the next one starts either way
+        Msg::ImageProcessed(name, result) => {
+            model.image_queue.finished(name, &result);
+            if let Ok(photo) = result {
+                orders.send_msg(Msg::NewCard(NewCardType::OnePhoto(photo)));
+            }
+            orders.send_msg(Msg::ImageQueueUpdated);
         }
-        // for the last row if it has less than columns number
-        // add empty divs as placeholders
-        if index == all_cards.len() - 1 {
-            let remaining = COLUMNS_NUMBER - row.len();
-            for _ in 0..remaining {
-                row.push(div![C!["column"]]);
+
+        Msg::DismissImageErrors => model.image_queue.dismiss_failed(),
+
+        Msg::DismissNotification(id) => model.notifications.dismiss(id),
+
+        // ******
+        // photos of real things for new cards, a tablet's camera is handier than finding files
+        // ******
+        Msg::OpenCamera => {
+            orders.perform_cmd(async { Msg::CameraStarted(camera::Camera::start().await) });
+        }
+
+        Msg::CameraStarted(Ok(camera)) => {
+            model.camera = Some(camera);
+            // the video element has to be on the page first
+            orders.after_next_render(|_| Msg::ShowCamera);
+        }
+
+        Msg::CameraStarted(Err(error)) => model.notifications.add(Level::Danger, error, orders),
+
+        Msg::ShowCamera => {
+            if let Some(camera) = &model.camera {
+                camera.show();
             }
+        }
 
-            all.push(div![C!["columns"], &row]);
+        // the camera stays open for the next photo
+        Msg::CapturePhoto => match model.camera.as_ref().map(camera::Camera::capture) {
+            Some(Ok(photo)) => model.add_card(NewCardType::OnePhoto(photo)),
+            Some(Err(error)) => model.notifications.add(Level::Danger, error, orders),
+            None => (),
+        },
+
+        Msg::CloseCamera => {
+            if let Some(camera) = model.camera.take() {
+                camera.stop();
+            }
+        }
+
+        // a screenshot or a copied picture, pasted anywhere on the editor
+        Msg::Paste(files) => {
+            if !model.game_started {
+                add_files(model, files, orders);
+            }
+        }
+    }
+}
+
+// a message for something that worked or didn't
+fn notify_result(model: &mut Model, result: Result<String, String>, orders: &mut impl Orders<Msg>) {
+    match result {
+        Ok(text) => model.notifications.add(Level::Success, text, orders),
+        Err(text) => model.notifications.add(Level::Danger, text, orders),
+    }
+}
+
+// dropped or pasted files: decks, spreadsheets and sounds by their names, and anything else
+// might be a picture, whatever its name says. the bytes decide
+fn add_files(
+    model: &mut Model,
+    files: impl IntoIterator<Item = web_sys::File>,
+    orders: &mut impl Orders<Msg>,
+) {
+    for file in files {
+        if let Some(msg) = import_file(&file) {
+            orders.send_msg(msg(file));
+            continue;
+        }
+        model.image_queue.add(file);
+    }
+    orders.send_msg(Msg::ImageQueueUpdated);
+}
+
+// a sound clip for a card, or why it couldn't have one
+fn set_audio(model: &mut Model, id: Ulid, result: Result<String, String>) {
+    match result {
+        Ok(clip) => {
+            if let Some(card) = model.words_list.get_mut(&id) {
+                card.audio = Some(clip);
+            }
+        }
+        Err(error) => {
+            model.card_errors.insert(id, error);
+        }
+    }
+}
+
+// exported decks, spreadsheets and sound files, told apart by their names
+fn import_file(file: &web_sys::File) -> Option<fn(web_sys::File) -> Msg> {
+    let name = file.name().to_lowercase();
+    if name.ends_with(".json") {
+        Some(Msg::ImportDeckFile)
+    } else if name.ends_with(".csv") {
+        Some(Msg::ImportCsvFile)
+    } else if audio::is_audio_file(&name) {
+        Some(Msg::AudioFileDropped)
+    } else {
+        None
+    }
+}
+
+// check a deck's json and add its cards to the list, returning how many were new. cards keep
+// their ids, so ones already in the list (the same file again, or a deck exported from it) are
+// left as they are
+fn add_deck(model: &mut Model, json: &str) -> Result<usize, String> {
+    let deck = deck::Deck::from_json(json.trim())?;
+    deck.validate()?;
+    // a fresh deck takes on the imported deck's language too
+    if model.words_list.is_empty() {
+        model.speech = deck.speech();
+        model.answer_policy = deck.answer_policy;
+    }
+    let new_cards: Vec<Card> = deck
+        .cards
+        .into_iter()
+        .filter(|card| !model.words_list.contains_key(&card.id))
+        .collect();
+    if new_cards.is_empty() {
+        return Err("every card in it is already in the list".to_string());
+    }
+    let count = new_cards.len();
+    model.add_cards(new_cards);
+    Ok(count)
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// from https://github.com/seed-rs/seed/blob/master/examples/drop_zone/src/lib.rs
+// set up drag events
+trait IntoDragEvent {
+    fn into_drag_event(self) -> DragEvent;
+}
+
+impl IntoDragEvent for Event {
+    fn into_drag_event(self) -> DragEvent {
+        self.dyn_into::<web_sys::DragEvent>()
+            .expect("cannot cast given event into DragEvent")
+    }
+}
+
+macro_rules! stop_and_prevent {
+    { $event:expr } => {
+        {
+            $event.stop_propagation();
+            $event.prevent_default();
         }
+    };
+}
+
+fn view(model: &Model) -> Vec<Node<Msg>> {
+    let page = if model.game_started {
+        game_page(model)
+    } else if model.setting_up_players {
+        vec![hot_seat::setup_view(&model.player_names)]
+    } else if model.library.is_choosing() {
+        vec![library::manager_view(&model.library, model.words_list.len()).map_msg(Msg::Library)]
+    } else {
+        vec![drop_target(model, new_words_page(model))]
+    };
+
+    vec![div![
+        C![theme::ROOT_CLASS],
+        theme::style_tag(&model.theme),
+        page,
+        notifications::view(&model.notifications),
+    ]]
+}
+
+// play the game page
+fn game_page(model: &Model) -> Vec<Node<Msg>> {
+    if model.lives.as_ref().is_some_and(lives::Lives::is_out) {
+        return vec![skins::board(
+            model.skin,
+            vec![
+                lives::game_over(&model.board),
+                model
+                    .webhook_result
+                    .as_ref()
+                    .map_or_else(|| empty![], webhook::status),
+                model
+                    .trace
+                    .as_ref()
+                    .map_or_else(|| empty![], trace::export_buttons),
+            ],
+        )];
+    }
+
+    if let Some(session) = model.session.as_ref().filter(|session| session.is_over()) {
+        return vec![skins::board(
+            model.skin,
+            vec![session::wrap_up(session, &model.board)],
+        )];
+    }
+
+    if model.editing_mid_game {
+        return vec![mid_game_edit::view(
+            &model.board,
+            &model.words_list,
+            &model.card_errors,
+        )];
+    }
+
+    if model.reviewing {
+        return vec![skins::board(
+            model.skin,
+            vec![review::view(
+                &model.board,
+                &model.misses,
+                &model.tile_flips,
+                &model.loaded_photos,
+                model.speedrun.as_ref(),
+                model.columns(),
+            )],
+        )];
+    }
+
+    if model.preparing_board {
+        return vec![skins::board(
+            model.skin,
+            vec![p![C!["title is-4"], "Getting the cards ready..."]],
+        )];
+    }
+
+    let columns = model.columns();
+    let card_back = model.skin.card_back();
+    let all_cards: Vec<Node<Msg>> = model
+        .board
+        .iter()
+        .enumerate()
+        .map(|(index, played_card)| {
+            print_card(
+                played_card,
+                index,
+                &card_back,
+                model.animations.flip_progress(index),
+                model.loaded_photos.contains(&played_card.card.id),
+                model.focused_tile == Some(index),
+            )
+        })
+        .collect();
+    let mut all = vec![
+        match &model.speedrun {
+            Some(speedrun) => speedrun::timer(speedrun),
+            // races and toddlers don't need the clock
+            None if model.race.is_some() || model.toddler => empty![],
+            None if model.countdown.is_some() => model.countdown.as_ref().map_or_else(
+                || empty![],
+                |countdown| countdown::hud(countdown, model.clock_now),
+            ),
+            None => clock::hud(
+                model.finished_seconds.unwrap_or_else(|| {
+                    ((model.clock_now - model.game_started_at) / 1000.0).max(0.0)
+                }),
+                model.best_times.best(&model.board),
+            ),
+        },
+        // racing boards count their own pairs
+        if model.race.is_some() {
+            empty![]
+        } else {
+            pairs_progress(model)
+        },
+        avatar::badge(model.avatar.as_ref(), &model.webhook.player),
+        model
+            .session
+            .as_ref()
+            .map_or_else(|| empty![], session::countdown),
+        model
+            .campaign
+            .as_ref()
+            .map_or_else(|| empty![], rounds::hud),
+        model.lives.as_ref().map_or_else(|| empty![], lives::hud),
+        if model.race.is_some() {
+            empty![]
+        } else {
+            pairs_hud(model)
+        },
+        // one click listener for the whole board instead of one per tile
+        match &model.race {
+            Some(race) => race::view(
+                race,
+                &all_cards,
+                pair_count(model).0,
+                &card_back,
+                &model.loaded_photos,
+            ),
+            None if model.list_board => list_board::view(&model.board, &model.announcement),
+            None => {
+                let board = pinch::view(
+                    &model.pinch,
+                    Msg::Pinch,
+                    div![
+                        attrs! {
+                            At::from("data-keyboard-board") => true,
+                            At::from("role") => "group",
+                            At::AriaLabel => "cards",
+                        },
+                        // what the last click did, for screen readers
+                        p![
+                            C!["is-sr-only"],
+                            attrs! {At::from("role") => "status", At::from("aria-live") => "assertive"},
+                            &model.announcement,
+                        ],
+                        board_rows(&all_cards, columns),
+                        ev(Ev::Click, board_click),
+                    ],
+                );
+                match &model.hot_seat {
+                    Some(hot_seat) => hot_seat::with_scoreboard(hot_seat, board),
+                    None => board,
+                }
+            }
+        },
+    ];
+
+    if let Some(countdown) = model
+        .countdown
+        .as_ref()
+        .filter(|countdown| countdown.is_out())
+    {
+        all.insert(0, countdown::time_up(countdown));
+    }
+
+    if model.toddler {
+        all.push(toddler::exit_button(model.holding_exit));
     }
 
     // decide whether to show a button that says show all or hide all
@@ -405,82 +3456,662 @@ fn game_page(model: &Model) -> Vec<Node<Msg>> {
     };
 
     // just add a couple of buttons at the bottom to make navigation easier
-    all.push(div![
-        button![
-            show_hide_all_button_text,
-            C!["button is-large is-danger"],
-            ev(Ev::Click, move |_| { Msg::ShowHideAll })
+    // (toddlers only get the hold to exit button)
+    if !model.toddler {
+        all.push(div![
+            button![
+                show_hide_all_button_text,
+                C!["button is-large is-danger"],
+                ev(Ev::Click, move |_| { Msg::ShowHideAll })
+            ],
+            card_zoom_slider(model),
+            br!(),
+            button![
+                "Play again!",
+                C!["button is-large is-success"],
+                ev(Ev::Click, move |_| { Msg::StartGame })
+            ],
+            button![
+                "Create New",
+                C!["button is-large is-warning"],
+                ev(Ev::Click, move |_| { Msg::ExitGame })
+            ],
+            // typos turn up mid game, fixing one shouldn't cost the game
+            IF!(model.race.is_none() => button![
+                "Fix a Card",
+                C!["button is-large is-info is-light"],
+                ev(Ev::Click, |_| Msg::EditMidGame),
+            ]),
+        ]);
+
+        all.push(shared_game_controls(model));
+    }
+
+    if let Some(spelling) = &model.spelling {
+        all.insert(0, spelling::view(spelling));
+    }
+
+    if let Some(id) = model.highlight {
+        all.push(match_highlight(model, id));
+    }
+
+    // every pair found (on either board in a race), so celebrate in the skin's style
+    let race_won = model.race.as_ref().is_some_and(race::Race::is_over);
+    if !model.board.is_empty()
+        && (model.board.iter().all(|card| card.matched) || race_won)
+        && !model.animations.is_flipping()
+    {
+        all.push(skins::celebration(
+            model.skin,
+            model.animations.celebration_progress(),
+        ));
+        if let Some(campaign) = &model.campaign {
+            all.insert(0, rounds::round_over(campaign));
+        }
+        if let Some(hot_seat) = &model.hot_seat {
+            all.insert(0, hot_seat::result(hot_seat));
+        }
+        if let Some(result) = &model.webhook_result {
+            all.push(webhook::status(result));
+        }
+        all.push(stickers::award_view(&model.stickers).map_msg(Msg::Stickers));
+        all.push(stickers::book_view(&model.stickers).map_msg(Msg::Stickers));
+        all.push(if model.toddler {
+            button![
+                "Play again!",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::StartGame),
+            ]
+        } else if model.campaign.is_some()
+            || model.link.as_ref().is_some_and(sync::Link::is_follower)
+        {
+            // rounds have their own buttons, and followers can only ask for the same again
+            empty![]
+        } else {
+            play_again_chooser(model)
+        });
+        // (stars and times are for one player)
+        if model.race.is_none() && model.hot_seat.is_none() && !model.toddler {
+            all.push(star_rating(model));
+        }
+        if let (Some(seconds), None, None, false) = (
+            model.finished_seconds,
+            &model.race,
+            &model.hot_seat,
+            model.toddler,
+        ) {
+            all.push(clock::result(
+                seconds,
+                model.best_times.best(&model.board),
+                model.new_best_time,
+            ));
+        }
+        if let Some((deck, place)) = &model.leaderboard_result {
+            all.push(leaderboard::view(
+                &leaderboard::title(deck),
+                model.leaderboards.top(deck),
+                *place,
+            ));
+        }
+        match &model.global_ranking {
+            Some(Ok(entries)) => {
+                all.push(leaderboard::view("🌍 School leaderboard", entries, None))
+            }
+            Some(Err(error)) => all.push(p![C!["help is-danger"], error]),
+            None => (),
+        }
+        if let Some(trace) = &model.trace {
+            all.push(trace::export_buttons(trace));
+        }
+        if let (Some(challenge), Some(seconds)) = (&model.challenge, model.finished_seconds) {
+            all.push(challenge::comparison(
+                challenge,
+                seconds,
+                game::moves(&model.tile_flips),
+            ));
+        }
+        if !model.toddler
+            && model.race.is_none()
+            && model.board_seed.is_some()
+            && model.finished_seconds.is_some()
+        {
+            all.push(challenge::share_button(model.challenge_status.as_ref()));
+        }
+        all.push(if model.toddler {
+            empty![]
+        } else {
+            button![
+                "Go over the pairs",
+                C!["button is-large is-info"],
+                ev(Ev::Click, |_| Msg::ToggleReview),
+            ]
+        });
+        if model.continuous {
+            all.push(p![C!["title is-4"], "A new board is on its way..."]);
+        }
+    }
+
+    vec![skins::board(model.skin, all)]
+}
+
+// after a win: the same pairs again, a new pick from the deck, or a different number of pairs
+fn play_again_chooser(model: &Model) -> Node<Msg> {
+    let deck_pairs = model.words_list.len();
+    div![
+        C!["box"],
+        p![C!["title is-5"], "Play again?"],
+        div![
+            C!["buttons"],
+            button![
+                "Same pairs, reshuffled",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::ReplaySamePairs),
+            ],
+            button![
+                "Rematch (same layout)",
+                C!["button is-large is-link"],
+                attrs! {At::Title => "the exact same board, to compare with the last player"},
+                ev(Ev::Click, |_| Msg::Rematch),
+            ],
+            IF!(game_pairs(&model.game_pairs).is_some_and(|pairs| pairs < deck_pairs) => button![
+                "New pairs from the deck",
+                C!["button is-large is-info"],
+                ev(Ev::Click, |_| Msg::StartGame),
+            ]),
         ],
-        br!(),
-        button![
-            "Play again!",
-            C!["button is-large is-success"],
-            ev(Ev::Click, move |_| { Msg::StartGame })
+        div![
+            C!["field has-addons"],
+            div![
+                C!["control"],
+                input![
+                    C!["input is-large"],
+                    style![St::Width => em(5)],
+                    attrs! {
+                        At::Type => "number",
+                        At::Min => 1,
+                        At::Max => deck_pairs,
+                        At::Value => model.game_pairs,
+                        At::Placeholder => deck_pairs,
+                    },
+                    input_ev(Ev::Input, Msg::SetGamePairs),
+                ],
+            ],
+            div![
+                C!["control"],
+                button![
+                    "Start with this many pairs",
+                    C!["button is-large"],
+                    ev(Ev::Click, |_| Msg::StartGame),
+                ],
+            ],
         ],
-        button![
-            "Create New",
-            C!["button is-large is-warning"],
-            ev(Ev::Click, move |_| { Msg::ExitGame })
-        ]
-    ]);
+        div![
+            C!["buttons"],
+            button![
+                "Edit These Cards",
+                C!["button is-large is-warning is-light"],
+                ev(Ev::Click, |_| Msg::EditDeck),
+            ],
+            IF!(!model.library.decks.is_empty() => button![
+                "Pick Another Deck",
+                C!["button is-large is-warning"],
+                ev(Ev::Click, |_| Msg::PickAnotherDeck),
+            ]),
+        ],
+    ]
+}
+
+// bigger cards for a projector, smaller ones to fit a phone
+fn card_zoom_slider(model: &Model) -> Node<Msg> {
+    let (min, max) = CARD_ZOOM_RANGE;
+    let zoom = model.card_zoom.unwrap_or(100);
+    label![
+        style![St::Margin => rem(0.5)],
+        "🔍 card size ",
+        input![
+            style![St::VerticalAlign => "middle"],
+            attrs! {
+                At::Type => "range",
+                At::Min => min,
+                At::Max => max,
+                At::Step => 10,
+                At::Value => zoom,
+            },
+            input_ev(Ev::Input, Msg::SetCardZoom),
+        ],
+        format!(" {}% ({} across)", zoom, model.columns()),
+    ]
+}
+
+// pairs found so far, and how many there are
+fn pair_count(model: &Model) -> (usize, usize) {
+    let found = model.board.iter().filter(|tile| tile.matched).count() / 2;
+    (found, model.board.len() / 2)
+}
+
+// a bar across the top that fills up as pairs are found
+fn pairs_progress(model: &Model) -> Node<Msg> {
+    let (found, pairs) = pair_count(model);
+    progress![
+        C!["progress is-success is-large"],
+        attrs! {At::Value => found, At::Max => pairs.max(1)},
+        format!("{} of {}", found, pairs),
+    ]
+}
+
+// how much of the board is left, big enough to read from the back of the room
+fn pairs_hud(model: &Model) -> Node<Msg> {
+    let (found, pairs) = pair_count(model);
+    p![
+        C!["title is-4"],
+        attrs! {At::from("role") => "status", At::from("aria-live") => "polite"},
+        format!(
+            "{} of {} pairs found · {} moves",
+            found,
+            pairs,
+            game::moves(&model.tile_flips)
+        ),
+    ]
+}
+
+// how the won board went: the moves it took and stars for them
+fn star_rating(model: &Model) -> Node<Msg> {
+    let moves = game::moves(&model.tile_flips);
+    let (_, pairs) = pair_count(model);
+    let stars = game::stars(moves, pairs);
+    div![
+        C!["box has-text-centered"],
+        p![
+            C!["title is-1"],
+            attrs! {At::AriaLabel => format!("{} of 3 stars", stars)},
+            (1..=3)
+                .map(|star| if star <= stars { "⭐" } else { "☆" })
+                .collect::<String>(),
+        ],
+        p![
+            C!["subtitle is-5"],
+            format!("{} pairs in {} moves", pairs, moves)
+        ],
+    ]
+}
+
+// the two tiles of a pair that was just found, side by side and big in the middle of the
+// screen, so the photo and the word get seen together. a click anywhere gets rid of it
+fn match_highlight(model: &Model, id: Ulid) -> Node<Msg> {
+    let loaded = model.loaded_photos.contains(&id);
+    div![
+        style![
+            St::Position => "fixed",
+            St::Top => 0,
+            St::Left => 0,
+            St::Width => vw(100),
+            St::Height => vh(100),
+            St::Display => "flex",
+            St::AlignItems => "center",
+            St::JustifyContent => "center",
+            St::Background => "rgba(10, 10, 10, 0.4)",
+            St::ZIndex => 20,
+        ],
+        custom![
+            Tag::Style,
+            "@keyframes match-pop { from { transform: scale(0.6); opacity: 0; } to { transform: scale(1); opacity: 1; } }"
+        ],
+        model
+            .board
+            .iter()
+            .filter(|tile| tile.card.id == id)
+            .map(|tile| {
+                div![
+                    C!["card"],
+                    style![
+                        St::Width => rem(16),
+                        St::Margin => rem(1),
+                        St::Animation => "match-pop 0.3s ease-out",
+                    ],
+                    card_front(&tile.card, tile.half, loaded),
+                ]
+            }),
+        ev(Ev::Click, move |_| Msg::HideHighlight(id)),
+    ]
+}
+
+// work out which tile a click on the board landed on from its data-tile attribute. a face up
+// tile turns everything back over, a face down one is a guess
+fn board_click(event: web_sys::Event) -> Option<Msg> {
+    let tile = event
+        .target()?
+        .dyn_into::<web_sys::Element>()
+        .ok()?
+        .closest("[data-tile]")
+        .ok()??;
+    let index = tile.get_attribute("data-tile")?.parse().ok()?;
+
+    Some(if tile.has_attribute("data-face-up") {
+        Msg::ResetClick
+    } else {
+        Msg::GuessCard(index)
+    })
+}
+
+// take cards and put them into divs for columns
+fn board_rows(all_cards: &[Node<Msg>], columns: usize) -> Vec<Node<Msg>> {
+    let mut row: Vec<Node<Msg>> = vec![];
+    let mut all: Vec<Node<Msg>> = vec![];
+    for (index, card) in all_cards.iter().enumerate() {
+        row.push(card.clone());
 
+        // put the correct number of cards in a row
+        if (index + 1) % columns == 0 {
+            all.push(div![C!["columns"], &row]);
+            row.clear();
+        }
+        // for the last row if it has less than columns number
+        // add empty divs as placeholders
+        if index == all_cards.len() - 1 {
+            let remaining = columns - row.len();
+            for _ in 0..remaining {
+                row.push(div![C!["column"]]);
+            }
+
+            all.push(div![C!["columns"], &row]);
+        }
+    }
     all
 }
 
+// the whole board face up, laid out like the game will be, to check it fits the screen
+fn board_preview(model: &Model) -> Node<Msg> {
+    let tiles: Vec<Node<Msg>> = model
+        .new_board()
+        .iter()
+        .map(|tile| {
+            div![
+                C!["column"],
+                div![
+                    C!["card"],
+                    card_front(
+                        &tile.card,
+                        tile.half,
+                        model.loaded_photos.contains(&tile.card.id)
+                    )
+                ]
+            ]
+        })
+        .collect();
+    let columns = model.columns();
+    let rows = tiles.len().div_ceil(columns);
+
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "Board preview"],
+        p![format!(
+            "{} tiles in {} columns and {} rows",
+            tiles.len(),
+            columns.min(tiles.len()),
+            rows
+        )],
+        if tiles.is_empty() {
+            p!["add some cards to see the board"]
+        } else {
+            skins::board(model.skin, board_rows(&tiles, columns))
+        },
+    ]
+}
+
+// show the game in another window, or what's going on if it already is
+fn shared_game_controls(model: &Model) -> Node<Msg> {
+    match model.link.as_ref().map(|link| link.role) {
+        None => button![
+            "Show on Another Screen",
+            C!["button is-info"],
+            ev(Ev::Click, |_| Msg::ShareGame),
+        ],
+        Some(sync::Role::Controller) => div![
+            p!["Open this page in another window and press \"Join Shared Game\" to show the game there too."],
+            button![
+                "Stop Sharing",
+                C!["button"],
+                ev(Ev::Click, |_| Msg::LeaveSharedGame),
+            ],
+        ],
+        Some(sync::Role::Follower) => div![
+            p!["Showing the game from another window."],
+            button![
+                "Leave",
+                C!["button"],
+                ev(Ev::Click, |_| Msg::LeaveSharedGame),
+            ],
+        ],
+    }
+}
+
 // print a card
-fn print_card(played_card: &PlayedCard, index: usize) -> Node<Msg> {
-    // make a more usable photo string
-    let card_image = match &played_card.card.photo {
-        Some(blob) => format!("<img src=\"{}\">", blob),
-        None => format!("<img src=\"{}\">", ARROW_IMAGE),
-    };
-    let card_text = match &played_card.card.text {
-        Some(text) => text,
-        None => "",
-    };
-    let question_image = format!("<img src=\"{}\">", QUESTION_IMG);
+fn print_card(
+    played_card: &PlayedCard,
+    index: usize,
+    card_back: &str,
+    flip: Option<f64>,
+    photo_loaded: bool,
+    focused: bool,
+) -> Node<Msg> {
+    // a tile that just turned over opens out from its middle
+    let flip_style = flip.map(|progress| style![St::Transform => format!("scaleX({})", progress)]);
+    // where the arrow keys are
+    let focus_ring = IF!(focused => style![
+        St::Outline => "4px solid #3273dc",
+        St::OutlineOffset => px(3),
+    ]);
 
     let show_card = played_card.displayed == CardState::FaceUp || played_card.matched;
+    // a real button, so it can be tabbed to and says what it is. (the picture and text inside
+    // are just for looking at, the label says it all)
+    let label = attrs! {At::AriaLabel => list_board::label(played_card, index)};
 
     if show_card {
         div![
             C!["column"],
-            div![
+            button![
+                C!["card"],
+                tile_button_style(),
+                label,
+                attrs! {At::from("data-tile") => index, At::from("data-face-up") => true},
+                flip_style,
+                focus_ring,
+                card_front(&played_card.card, played_card.half, photo_loaded),
+            ]
+        ]
+    } else {
+        div![
+            C!["column"],
+            button![
                 C!["card"],
+                tile_button_style(),
+                label,
                 div![
                     C!["card-image"],
-                    figure!(C!["image is-square is-fullwidth"], raw!(&card_image),)
+                    figure![
+                        C!["image is-square is-fullwidth"],
+                        // (as an attribute, nothing in the skin's card back can turn into html)
+                        img![attrs! {At::Src => card_back, At::Alt => "face down card"}],
+                    ]
                 ],
                 div![
                     C!["card-content"],
                     div![
                         C!["media"],
-                        div![C!["media-content"], p!(C!["title is-4"], card_text,)]
+                        div![C!["media-content"], p!(C!["title is-4"], index + 1,)]
                     ]
                 ],
-                ev(Ev::Click, move |_| Msg::ResetClick),
+                attrs! {At::from("data-tile") => index},
+                flip_style,
+                focus_ring,
+            ]
+        ]
+    }
+}
+
+// a button that looks like the card it is, nothing of the browser's button look
+fn tile_button_style() -> Style {
+    style![
+        St::Display => "block",
+        St::Width => percent(100),
+        St::Padding => 0,
+        St::Border => "none",
+        St::Font => "inherit",
+        St::Color => "inherit",
+        St::TextAlign => "inherit",
+        St::Cursor => "pointer",
+    ]
+}
+
+// the picture and text of a face up card, the same in the game and in the editor's preview
+// `loaded` is false until the card's photo has finished loading, a shimmering box stands in
+// for it until then
+fn card_front(card: &Card, half: PairHalf, loaded: bool) -> Vec<Node<Msg>> {
+    // (the B side of a pair only shows the partner text)
+    let card_image = match (&card.photo, half) {
+        (Some(blob), PairHalf::A) => photo_img(
+            card.id,
+            blob,
+            card.preview.as_deref(),
+            loaded,
+            card.text.as_deref().unwrap_or(""),
+        ),
+        _ => img![attrs! {At::Src => ARROW_IMAGE, At::Alt => ""}],
+    };
+    let card_text = match (half, &card.sound) {
+        (PairHalf::A, _) => card.text.as_deref().unwrap_or(""),
+        // sound tiles only show a speaker, the point is to listen
+        (PairHalf::B, Some(_)) => "🔊",
+        (PairHalf::B, None) => card.text_b.as_deref().unwrap_or(""),
+    };
+
+    vec![
+        div![
+            C!["card-image"],
+            figure!(C!["image is-square is-fullwidth"], card_image)
+        ],
+        div![
+            C!["card-content"],
+            div![
+                C!["media"],
+                div![
+                    C!["media-content"],
+                    p!(
+                        C!["title is-4"],
+                        card_text,
+                        IF!(card.audio.is_some() => span![" 🔊"])
+                    )
+                ]
+            ]
+        ],
+    ]
+}
+
+// play, record, pick or remove a card's sound clip
+fn audio_controls(model: &Model, card: &Card) -> Node<Msg> {
+    let id = card.id;
+    let recording_this = model
+        .recording
+        .as_ref()
+        .is_some_and(|(recording, _)| *recording == id);
+    div![
+        C!["buttons"],
+        card.audio.clone().map(|clip| button![
+            "▶ sound",
+            C!["button is-small"],
+            ev(Ev::Click, move |_| Msg::PlayClip(clip)),
+        ]),
+        if recording_this {
+            button![
+                "⏹ stop",
+                C!["button is-small is-danger"],
+                ev(Ev::Click, |_| Msg::StopRecording),
             ]
-        ]
+        } else {
+            button![
+                "🎤 record",
+                C!["button is-small"],
+                attrs! {At::Disabled => model.recording.is_some().as_at_value()},
+                ev(Ev::Click, move |_| Msg::RecordAudio(id)),
+            ]
+        },
+        label![
+            C!["button is-small"],
+            "🎵 sound file...",
+            input![
+                attrs! {At::Type => "file", At::Accept => "audio/*"},
+                style![St::Display => "none"],
+                ev(Ev::Change, move |event| {
+                    let file = event
+                        .target()?
+                        .dyn_into::<web_sys::HtmlInputElement>()
+                        .ok()?
+                        .files()?
+                        .get(0)?;
+                    Some(Msg::AttachAudio { id, file })
+                }),
+            ],
+        ],
+        IF!(card.audio.is_some() => button![
+            "remove sound",
+            C!["button is-small is-light"],
+            ev(Ev::Click, move |_| Msg::RemoveAudio(id)),
+        ]),
+    ]
+}
+
+// a card's photo, reporting when it's loaded so the skeleton can go away
+// `alt` is the card's text, a photo of a dog on a "dog" card is a dog
+fn photo_img(id: Ulid, photo: &str, preview: Option<&str>, loaded: bool, alt: &str) -> Node<Msg> {
+    // the blurry preview if there is one, a shimmer if not
+    let (class, background) = match preview {
+        Some(preview) if !loaded => (theme::PREVIEW_CLASS, Some(format!("url({})", preview))),
+        _ => (theme::SKELETON_CLASS, None),
+    };
+    img![
+        C![IF!(!loaded => class)],
+        background.map(|background| style![St::BackgroundImage => background]),
+        attrs! {At::Src => photo, At::Alt => alt},
+        IF!(!loaded => ev(Ev::Load, move |_| Msg::PhotoLoaded(id))),
+    ]
+}
+
+// the card's tiles at the size they'll have on the board, so long words and odd photos show up
+// before the game starts
+fn card_preview(card: &Card, loaded: bool, columns: usize) -> Node<Msg> {
+    let halves: &[PairHalf] = if card.text_b.is_some() || card.sound.is_some() {
+        &[PairHalf::A, PairHalf::B]
     } else {
-        div![
+        &[PairHalf::A]
+    };
+
+    div![
+        C!["columns is-mobile"],
+        style![St::Width => format!("calc({} * 100vw / {})", halves.len(), columns)],
+        halves.iter().map(|half| div![
             C!["column"],
-            div![
-                C!["card"],
-                div![
-                    C!["card-image"],
-                    figure!(C!["image is-square is-fullwidth"], raw!(&question_image),)
-                ],
-                div![
-                    C!["card-content"],
-                    div![
-                        C!["media"],
-                        div![C!["media-content"], p!(C!["title is-4"], index + 1,)]
-                    ]
-                ],
-                ev(Ev::Click, move |_| Msg::GuessCard(index))
-            ]
-        ]
-    }
+            div![C!["card"], card_front(card, *half, loaded)]
+        ]),
+    ]
+}
+
+// the grip a row is dragged by, the rest of the row has text to select
+fn move_handle(id: Ulid) -> Node<Msg> {
+    span![
+        "☰",
+        attrs! {At::Draggable => "true", At::Title => "drag to move this card"},
+        style![St::Cursor => "grab", St::FontSize => rem(1.5), St::Padding => "5px"],
+        ev(Ev::DragStart, move |event| {
+            if let Some(transfer) = event.into_drag_event().data_transfer() {
+                transfer.set_effect_allowed("move");
+                // firefox won't start a drag that's carrying nothing
+                let _garbage = transfer.set_data("text/plain", "");
+            }
+            Msg::StartMovingCard(id)
+        }),
+        ev(Ev::DragEnd, |_| Msg::StopMovingCard),
+    ]
 }
 
 // show the new words page
@@ -488,30 +4119,76 @@ fn new_words_page(model: &Model) -> Vec<Node<Msg>> {
     /*
     the list of the words and formatted
      */
+    let pages = model.words_list.len().div_ceil(EDITOR_PAGE_SIZE).max(1);
+    // deleting cards can leave the page past the end
+    let page = model.editor_page.min(pages - 1);
+
     let existing_words = model
-        .words_list
-        .iter()
-        .map(|(id, card)| {
+        .cards()
+        .skip(page * EDITOR_PAGE_SIZE)
+        .take(EDITOR_PAGE_SIZE)
+        .map(|card| {
+            let id = &card.id;
             /*
             information for the html: image blob and flashcard word title
              */
-            let image_blob = match &card.photo {
-                Some(text) => format!("<img src=\"{}\">", text),
-                None => "".to_string(),
+            // rows off screen get an empty box instead of their pictures
+            let in_view = model.row_observer.is_none() || model.visible_rows.contains(id);
+            let placeholder = || {
+                div![style![
+                    St::Width => px(thumbnail::THUMB_SIZE),
+                    St::Height => px(thumbnail::THUMB_SIZE),
+                ]]
             };
             let card_text = match &card.text {
                 Some(text) => text,
                 None => "",
             };
+            let card_text_b = card.text_b.as_deref().unwrap_or("");
             let this_id = *id;
 
             tr!(
+                attrs! {At::from(visible_rows::ROW_ATTRIBUTE) => this_id.to_string()},
+                IF!(model.moving_card == Some(this_id) => style![St::Opacity => 0.5]),
+                IF!(model.move_target == Some(this_id) && model.moving_card != Some(this_id) =>
+                    style![St::Outline => "3px dashed var(--ms-drop-zone-border)"]),
+                ev(Ev::DragEnter, move |_| Msg::MoveCardOver(this_id)),
+                // without this the browser won't let anything drop on the row
+                ev(Ev::DragOver, |event| {
+                    event.prevent_default();
+                    None::<Msg>
+                }),
+                ev(Ev::Drop, move |event| {
+                    event.prevent_default();
+                    Msg::MoveCard(this_id)
+                }),
+                td!(move_handle(this_id)),
                 td!(div![
-                    IF!(!image_blob.is_empty() => raw!(&image_blob)),
+                    match &card.photo {
+                        Some(photo) if in_view => {
+                            let mut photo = photo_img(
+                                this_id,
+                                photo,
+                                card.preview.as_deref(),
+                                model.loaded_photos.contains(id),
+                                card.text.as_deref().unwrap_or(""),
+                            );
+                            photo.add_attr("loading", "lazy");
+                            photo.add_attr("decoding", "async");
+                            photo
+                        }
+                        Some(_) => placeholder(),
+                        None => empty![],
+                    },
                     style![
                         St::Margin => "5px",
                     ]
                 ],),
+                td!(if in_view {
+                    card_preview(card, model.loaded_photos.contains(id), model.columns())
+                } else {
+                    placeholder()
+                }),
                 td!(div![
                     "show vocab word (optional)",
                     br!(),
@@ -522,11 +4199,72 @@ fn new_words_page(model: &Model) -> Vec<Node<Msg>> {
                             text: word
                         }),
                     ],
+                    br!(),
+                    card.sound.as_ref().map(|sound| {
+                        let sound = sound.clone();
+                        div![
+                            format!("the other card says \"{}\" ", sound),
+                            button![
+                                "🔊",
+                                C!["button is-small"],
+                                ev(Ev::Click, move |_| Msg::Say(sound)),
+                            ],
+                        ]
+                    }),
+                    audio_controls(model, card),
+                    "matching text for the other card, like a translation (optional)",
+                    br!(),
+                    input![
+                        attrs! {At::Value => card_text_b},
+                        input_ev(Ev::Input, move |text| Msg::UpdateCardTextB {
+                            id: this_id,
+                            text
+                        }),
+                    ],
+                    IF!(model.lookup.is_configured() && card.text.is_some() => button![
+                        "suggest",
+                        C![
+                            "button is-small is-info",
+                            IF!(model.suggesting.contains(&this_id) => "is-loading")
+                        ],
+                        ev(Ev::Click, move |_| Msg::Suggest(this_id)),
+                    ]),
+                    br!(),
                     button![
                         "delete",
                         ev(Ev::Click, move |_| Msg::DeleteCard(this_id)),
                         C!["button is-small is-danger"]
                     ],
+                    button![
+                        "duplicate",
+                        ev(Ev::Click, move |_| Msg::DuplicateCard(this_id)),
+                        C!["button is-small"]
+                    ],
+                    photo_edit::picker(
+                        this_id,
+                        if card.photo.is_some() {
+                            "change photo..."
+                        } else {
+                            "add photo..."
+                        }
+                    ),
+                    IF!(card.photo.is_some() => button![
+                        "turn/trim photo",
+                        ev(Ev::Click, move |_| Msg::EditPhoto(this_id)),
+                        C!["button is-small"]
+                    ]),
+                    IF!(model.image_gen.is_configured() && card.text.is_some() => button![
+                        "generate picture",
+                        C![
+                            "button is-small is-info",
+                            IF!(model.generating.contains(&this_id) => "is-loading")
+                        ],
+                        ev(Ev::Click, move |_| Msg::GeneratePicture(this_id)),
+                    ]),
+                    model
+                        .card_errors
+                        .get(&this_id)
+                        .map(|error| p![C!["help is-danger"], error]),
                     style![
                         St::Margin => "5px"
                     ]
@@ -550,6 +4288,29 @@ fn new_words_page(model: &Model) -> Vec<Node<Msg>> {
         ev(Ev::Click, move |_| Msg::ExitGame),
     ];
 
+    let undo_button: Node<Msg> = button![
+        "Undo",
+        C!["button is-large"],
+        attrs! {At::Disabled => (!model.history.can_undo()).as_at_value(), At::Title => "Ctrl+Z"},
+        ev(Ev::Click, |_| Msg::Undo),
+    ];
+    let redo_button: Node<Msg> = button![
+        "Redo",
+        C!["button is-large"],
+        attrs! {At::Disabled => (!model.history.can_redo()).as_at_value(), At::Title => "Ctrl+Y"},
+        ev(Ev::Click, |_| Msg::Redo),
+    ];
+
+    let preview_button: Node<Msg> = button![
+        if model.board_preview_open {
+            "Hide Board Preview"
+        } else {
+            "Preview Board"
+        },
+        C!["button is-large"],
+        ev(Ev::Click, |_| Msg::ToggleBoardPreview),
+    ];
+
     // add a start game button
     let start_game: Node<Msg> = button![
         "Start Game",
@@ -557,17 +4318,826 @@ fn new_words_page(model: &Model) -> Vec<Node<Msg>> {
         ev(Ev::Click, move |_| { Msg::StartGame })
     ];
 
+    // fill in every card's matching text at once
+    let suggest_all_button: Node<Msg> = if model.lookup.is_configured() {
+        button![
+            "Suggest All",
+            C!["button is-large is-info"],
+            attrs! {At::Disabled => model.batch_lookup.is_some().as_at_value()},
+            ev(Ev::Click, move |_| Msg::SuggestAll),
+        ]
+    } else {
+        empty![]
+    };
+
+    let generate_button: Node<Msg> = button![
+        "Generate",
+        C!["button is-large is-link is-light"],
+        ev(Ev::Click, move |_| Msg::Generator(generators::Msg::Toggle)),
+    ];
+
+    let export_button: Node<Msg> = button![
+        "Export Playable Game",
+        C!["button is-large is-link is-light"],
+        attrs! {At::Disabled => (model.words_list.len() < 2).as_at_value()},
+        ev(Ev::Click, move |_| Msg::ExportPlayable),
+    ];
+
+    let export_deck_button: Node<Msg> = button![
+        "Export Deck",
+        C!["button is-large is-link is-light"],
+        attrs! {At::Disabled => model.words_list.is_empty().as_at_value()},
+        ev(Ev::Click, |_| Msg::ExportDeck),
+    ];
+
+    let copy_button: Node<Msg> = button![
+        "Copy Deck",
+        C!["button is-large is-link is-light"],
+        attrs! {At::Disabled => model.words_list.is_empty().as_at_value()},
+        ev(Ev::Click, move |_| Msg::CopyDeck),
+    ];
+    let share_button: Node<Msg> = button![
+        "Share Deck Link",
+        C!["button is-large is-link is-light"],
+        attrs! {
+            At::Disabled => model.words_list.is_empty().as_at_value(),
+            At::Title => "copy a link that opens these cards' words",
+        },
+        ev(Ev::Click, |_| Msg::ShareDeck),
+    ];
+
+    let camera_button: Node<Msg> = button![
+        "📷 Take Photo",
+        C!["button is-large is-link"],
+        ev(Ev::Click, |_| Msg::OpenCamera),
+    ];
+
+    let paste_button: Node<Msg> = button![
+        "Import from Clipboard",
+        C!["button is-large is-link is-light"],
+        ev(Ev::Click, move |_| Msg::PasteDeck),
+    ];
+
+    // follow a game that's being played in another window
+    let join_button: Node<Msg> = if model.link.is_some() {
+        button![
+            "Waiting for the other window... (Cancel)",
+            C!["button is-large"],
+            ev(Ev::Click, |_| Msg::LeaveSharedGame),
+        ]
+    } else {
+        button![
+            "Join Shared Game",
+            C!["button is-large"],
+            ev(Ev::Click, |_| Msg::JoinSharedGame),
+        ]
+    };
+
+    let decks_button: Node<Msg> = IF!(!model.library.decks.is_empty() => button![
+        "All Decks",
+        C!["button is-large"],
+        ev(Ev::Click, |_| Msg::Library(library::Msg::ChooseDeck)),
+    ])
+    .unwrap_or_else(|| empty![]);
+    let library_button: Node<Msg> = button![
+        "Saved Decks",
+        C!["button is-large", IF!(model.library.open => "is-link")],
+        ev(Ev::Click, |_| Msg::Library(library::Msg::Toggle)),
+    ];
+
+    let stickers_button: Node<Msg> = button![
+        "Sticker Book",
+        C![
+            "button is-large",
+            IF!(model.stickers.is_open() => "is-link")
+        ],
+        ev(Ev::Click, |_| Msg::Stickers(stickers::Msg::Toggle)),
+    ];
+
+    let settings_button: Node<Msg> = button![
+        if model.settings_open {
+            "Hide Settings"
+        } else {
+            "Settings"
+        },
+        C!["button is-large is-info"],
+        ev(Ev::Click, move |_| Msg::ToggleSettings),
+    ];
+
     /*
     put it all into a Vec to return
      */
     vec![
+        model
+            .resumable
+            .as_ref()
+            .map_or_else(|| empty![], resume_banner),
+        match &model.draft {
+            Some(draft) if !model.game_started => draft_banner(draft),
+            _ => empty![],
+        },
+        if model.words_list.is_empty() && !model.wizard_dismissed {
+            wizard::view()
+        } else {
+            empty![]
+        },
         drag_and_drop_area(model),
         br!(),
+        stock_photos::view(model),
+        generators::view(&model.generators).map_msg(Msg::Generator),
+        library::view(
+            &model.library,
+            model.words_list.is_empty(),
+            &model.leaderboards,
+        )
+        .map_msg(Msg::Library),
+        stickers::book_view(&model.stickers).map_msg(Msg::Stickers),
+        model
+            .batch_lookup
+            .as_ref()
+            .map_or_else(|| empty![], lookup::review_view),
         table![existing_words, C!["table is-striped"]],
+        editor_pager(page, pages),
         add_new_button,
+        camera_button,
+        generate_button,
+        suggest_all_button,
         clear_list_button,
+        undo_button,
+        redo_button,
         br!(),
+        preview_button,
         start_game,
+        export_button,
+        export_deck_button,
+        copy_button,
+        share_button,
+        paste_button,
+        join_button,
+        decks_button,
+        library_button,
+        stickers_button,
+        settings_button,
+        model
+            .deleted_card
+            .as_ref()
+            .map_or_else(|| empty![], |(_, name)| delete_notice(name)),
+        if model.confirm_skipped {
+            skipped_cards_dialog(model)
+        } else {
+            empty![]
+        },
+        if model.board_preview_open {
+            board_preview(model)
+        } else {
+            empty![]
+        },
+        if model.settings_open {
+            settings_panel(model)
+        } else {
+            empty![]
+        },
+        model.camera.as_ref().map_or_else(|| empty![], camera::view),
+        model
+            .photo_edit
+            .as_ref()
+            .map_or_else(|| empty![], photo_edit::view),
+    ]
+}
+
+// there's an unfinished game saved from last time
+fn resume_banner(saved: &saved_game::SavedGame) -> Node<Msg> {
+    div![
+        C!["box"],
+        p![format!(
+            "You have an unfinished game ({} of {} pairs found). Keep playing?",
+            saved.pairs_found(),
+            saved.pairs()
+        )],
+        div![
+            C!["buttons"],
+            button![
+                "Resume",
+                C!["button is-success"],
+                ev(Ev::Click, |_| Msg::ResumeGame),
+            ],
+            button![
+                "Discard",
+                C!["button"],
+                ev(Ev::Click, |_| Msg::DiscardSavedGame),
+            ],
+        ],
+    ]
+}
+
+// cards were edited last time that didn't go into the word list
+fn draft_banner(draft: &draft::Draft) -> Node<Msg> {
+    div![
+        C!["box"],
+        p![format!(
+            "There's an unsaved draft with {} cards from last time. Restore it?",
+            draft.len()
+        )],
+        div![
+            C!["buttons"],
+            button![
+                "Restore Draft",
+                C!["button is-success"],
+                ev(Ev::Click, |_| Msg::RestoreDraft),
+            ],
+            button![
+                "Discard",
+                C!["button"],
+                ev(Ev::Click, |_| Msg::DiscardDraft),
+            ],
+        ],
+    ]
+}
+
+// previous/next and a button per page, only when the deck doesn't fit on one
+fn editor_pager(page: usize, pages: usize) -> Node<Msg> {
+    if pages < 2 {
+        return empty![];
+    }
+    div![
+        C!["buttons"],
+        button![
+            "Previous",
+            C!["button"],
+            attrs! {At::Disabled => (page == 0).as_at_value()},
+            ev(Ev::Click, move |_| Msg::SetEditorPage(
+                page.saturating_sub(1)
+            )),
+        ],
+        (0..pages).map(|number| {
+            button![
+                number + 1,
+                C!["button", IF!(number == page => "is-link")],
+                ev(Ev::Click, move |_| Msg::SetEditorPage(number)),
+            ]
+        }),
+        button![
+            "Next",
+            C!["button"],
+            attrs! {At::Disabled => (page + 1 == pages).as_at_value()},
+            ev(Ev::Click, move |_| Msg::SetEditorPage(page + 1)),
+        ],
+    ]
+}
+
+// a deleted card (and its carefully picked photo) is one click from coming back
+fn delete_notice(name: &str) -> Node<Msg> {
+    div![
+        C!["notification is-warning"],
+        style![
+            St::Position => "fixed",
+            St::Bottom => rem(1),
+            St::Left => percent(50),
+            St::Transform => "translateX(-50%)",
+            St::ZIndex => 30,
+        ],
+        attrs! {At::from("role") => "status"},
+        format!("Deleted {} ", name),
+        button![
+            "Undo",
+            C!["button is-small"],
+            attrs! {At::Title => "Ctrl+Z"},
+            ev(Ev::Click, |_| Msg::Undo),
+        ],
+    ]
+}
+
+// some cards can't be played, ask before starting without them
+fn skipped_cards_dialog(model: &Model) -> Node<Msg> {
+    let skipped = model.skipped_cards();
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "Some cards will be left out"],
+        ul![skipped.iter().map(|(card, reason)| {
+            li![format!(
+                "{}: {}",
+                card.text
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|text| !text.is_empty())
+                    .unwrap_or("a card with no text"),
+                reason
+            )]
+        })],
+        br![],
+        div![
+            C!["buttons"],
+            button!["Fix Them", C!["button"], ev(Ev::Click, |_| Msg::FixSkipped),],
+            button![
+                format!("Delete {} and Start", skipped.len()),
+                C!["button is-danger"],
+                ev(Ev::Click, |_| Msg::DeleteSkipped),
+            ],
+            button![
+                "Start Without Them",
+                C!["button is-success"],
+                ev(Ev::Click, |_| Msg::StartSkipping),
+            ],
+        ],
+    ]
+}
+
+// deck and app settings
+fn settings_panel(model: &Model) -> Node<Msg> {
+    div![
+        C!["box"],
+        speech_settings(model),
+        hr![],
+        stock_photo_settings(model),
+        hr![],
+        image_gen_settings(model),
+        hr![],
+        lookup_settings(model),
+        hr![],
+        webhook_settings(model),
+        hr![],
+        skin_settings(model),
+        hr![],
+        play_settings(model),
+    ]
+}
+
+fn speech_settings(model: &Model) -> Node<Msg> {
+    if !speech::is_supported() {
+        return p!["this browser can't read cards out loud"];
+    }
+
+    // every language the browser has a voice for, as suggestions for the input
+    let mut languages: Vec<&str> = model
+        .voices
+        .iter()
+        .map(|voice| voice.lang.as_str())
+        .collect();
+    languages.sort_unstable();
+    languages.dedup();
+
+    let voice_options = model
+        .voices
+        .iter()
+        .filter(|voice| voice.speaks(&model.speech.lang))
+        .map(|voice| {
+            option![
+                attrs! {
+                    At::Value => voice.name,
+                    At::Selected => (Some(&voice.name) == model.speech.voice.as_ref()).as_at_value(),
+                },
+                format!("{} ({})", voice.name, voice.lang)
+            ]
+        });
+
+    div![
+        div![
+            C!["field"],
+            label![C!["label"], "speech language (like en-US or es-ES)"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Value => model.speech.lang,
+                    At::List => "speech-languages",
+                },
+                input_ev(Ev::Change, Msg::SetSpeechLang),
+            ],
+            datalist![
+                id!["speech-languages"],
+                languages
+                    .iter()
+                    .map(|lang| option![attrs! {At::Value => lang}])
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "voice"],
+            div![
+                C!["select"],
+                select![
+                    option![
+                        attrs! {
+                            At::Value => "",
+                            At::Selected => model.speech.voice.is_none().as_at_value(),
+                        },
+                        "default for this language"
+                    ],
+                    voice_options,
+                    input_ev(Ev::Change, Msg::SetSpeechVoice),
+                ],
+            ],
+        ],
+        button![
+            "Test voice",
+            C!["button is-small is-info"],
+            ev(Ev::Click, move |_| Msg::PreviewVoice),
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.speech.read_words.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleReadWords),
+                ],
+                " read each word out loud when its card turns over (saved with the deck)",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.spelling_practice.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleSpellingPractice),
+                ],
+                " spelling practice: type the word after every pair found",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["label"],
+                "how close the spelling has to be (saved with the deck)"
+            ],
+            div![
+                C!["select"],
+                select![
+                    game::AnswerPolicy::ALL.iter().map(|policy| option![
+                        attrs! {
+                            At::Value => policy.name(),
+                            At::Selected => (*policy == model.answer_policy).as_at_value(),
+                        },
+                        policy.name()
+                    ]),
+                    input_ev(Ev::Change, Msg::SetAnswerPolicy),
+                ],
+            ],
+        ],
+    ]
+}
+
+fn stock_photo_settings(model: &Model) -> Node<Msg> {
+    let settings = &model.stock_photos;
+    div![
+        div![
+            C!["field"],
+            label![C!["label"], "picture search site"],
+            div![
+                C!["select"],
+                select![
+                    stock_photos::Provider::ALL.iter().map(|provider| option![
+                        attrs! {
+                            At::Value => provider.name(),
+                            At::Selected => (*provider == settings.provider).as_at_value(),
+                        },
+                        provider.name()
+                    ]),
+                    input_ev(Ev::Change, Msg::SetPhotoProvider),
+                ],
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "api key"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Type => "password",
+                    At::Value => settings.api_key,
+                    At::Placeholder => if settings.provider.needs_key() { "required" } else { "optional" },
+                },
+                input_ev(Ev::Change, Msg::SetPhotoApiKey),
+            ],
+        ],
+    ]
+}
+
+fn image_gen_settings(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["field"],
+            label![C!["label"], "picture generation endpoint (optional)"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Type => "url",
+                    At::Value => model.image_gen.endpoint,
+                    At::Placeholder => "https://example.com/v1/images/generations",
+                },
+                input_ev(Ev::Change, Msg::SetImageGenEndpoint),
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "picture generation api key"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Type => "password",
+                    At::Value => model.image_gen.api_key,
+                },
+                input_ev(Ev::Change, Msg::SetImageGenKey),
+            ],
+        ],
+    ]
+}
+
+fn webhook_settings(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["field"],
+            label![C!["label"], "school leaderboard server (optional)"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Type => "url",
+                    At::Value => model.global_leaderboard.url,
+                    At::Placeholder => "https://example.com/leaderboard",
+                },
+                input_ev(Ev::Change, Msg::SetGlobalLeaderboardUrl),
+            ],
+            p![
+                C!["help"],
+                "wins on saved decks are sent there with the player's name, and the rankings come back for the win screen",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "send results to (optional)"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Type => "url",
+                    At::Value => model.webhook.url,
+                    At::Placeholder => "https://example.com/webhook",
+                },
+                input_ev(Ev::Change, Msg::SetWebhookUrl),
+            ],
+            p![
+                C!["help"],
+                "every finished game is POSTed there as json: deck, player, moves, time and missed pairs",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "player or team"],
+            input![
+                C!["input"],
+                attrs! {At::Value => model.webhook.player},
+                input_ev(Ev::Input, Msg::SetPlayerName),
+            ],
+        ],
+        avatar::picker(model.avatar.as_ref(), model.avatar_error.as_ref()),
+    ]
+}
+
+fn lookup_settings(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["field"],
+            label![C!["label"], "dictionary/translation url (optional)"],
+            input![
+                C!["input"],
+                attrs! {
+                    At::Value => model.lookup.url_template,
+                    At::Placeholder => "https://example.com/lookup?word={word}&lang={lang}",
+                },
+                input_ev(Ev::Change, Msg::SetLookupUrl),
+            ],
+            p![
+                C!["help"],
+                "{word} becomes the card text and {lang} the speech language"
+            ],
+        ],
+        div![
+            C!["buttons"],
+            lookup::PRESETS.iter().map(|(name, url)| {
+                let url = (*url).to_string();
+                button![
+                    name,
+                    C!["button is-small"],
+                    ev(Ev::Click, move |_| Msg::SetLookupUrl(url)),
+                ]
+            }),
+        ],
+    ]
+}
+
+fn skin_settings(model: &Model) -> Node<Msg> {
+    div![
+        C!["field"],
+        label![C!["label"], "board theme"],
+        div![
+            C!["select"],
+            select![
+                skins::Skin::ALL.iter().map(|skin| option![
+                    attrs! {
+                        At::Value => skin.name(),
+                        At::Selected => (*skin == model.skin).as_at_value(),
+                    },
+                    skin.name()
+                ]),
+                input_ev(Ev::Change, Msg::SetSkin),
+            ],
+        ],
+    ]
+}
+
+fn play_settings(model: &Model) -> Node<Msg> {
+    div![
+        div![
+            C!["field"],
+            label![C!["label"], "how to play"],
+            div![
+                C!["select"],
+                select![
+                    game::Mode::ALL.iter().map(|mode| option![
+                        attrs! {
+                            At::Value => mode.name(),
+                            At::Selected => (*mode == model.mode).as_at_value(),
+                        },
+                        mode.name()
+                    ]),
+                    input_ev(Ev::Change, Msg::SetMode),
+                ],
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "after a wrong pair"],
+            div![
+                C!["select"],
+                select![
+                    game::MissReset::ALL.iter().map(|miss_reset| option![
+                        attrs! {
+                            At::Value => miss_reset.name(),
+                            At::Selected => (*miss_reset == model.miss_reset).as_at_value(),
+                        },
+                        miss_reset.name()
+                    ]),
+                    input_ev(Ev::Change, Msg::SetMissReset),
+                ],
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "difficulty (how long a lone card stays up)"],
+            div![
+                C!["select"],
+                select![
+                    game::Difficulty::ALL.iter().map(|difficulty| option![
+                        attrs! {
+                            At::Value => difficulty.name(),
+                            At::Selected => (*difficulty == model.difficulty).as_at_value(),
+                        },
+                        difficulty.name()
+                    ]),
+                    input_ev(Ev::Change, Msg::SetDifficulty),
+                ],
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "cards across the board"],
+            div![
+                C!["select"],
+                select![
+                    COLUMN_CHOICES.map(|columns| option![
+                        attrs! {
+                            At::Value => columns,
+                            At::Selected => (model.column_count.unwrap_or(COLUMNS_NUMBER) == columns).as_at_value(),
+                        },
+                        columns.to_string()
+                    ]),
+                    input_ev(Ev::Change, Msg::SetColumnCount),
+                ],
+            ],
+        ],
+        div![
+            C!["field"],
+            label![C!["label"], "pairs per game (empty for the whole deck)"],
+            input![
+                C!["input"],
+                style![St::Width => em(6)],
+                attrs! {At::Type => "number", At::Min => 1, At::Value => model.game_pairs},
+                input_ev(Ev::Input, Msg::SetGamePairs),
+            ],
+        ],
+        IF!(model.mode == game::Mode::Countdown => div![
+            C!["field"],
+            label![C!["label"], "seconds to clear the board"],
+            input![
+                C!["input"],
+                style![St::Width => em(6)],
+                attrs! {
+                    At::Type => "number",
+                    At::Min => 1,
+                    At::Value => model.countdown_setting,
+                    At::Placeholder => countdown::seconds(""),
+                },
+                input_ev(Ev::Input, Msg::SetCountdown),
+            ],
+        ]),
+        IF!(model.mode == game::Mode::Lives => div![
+            C!["field"],
+            label![C!["label"], "lives (wrong guesses allowed)"],
+            input![
+                C!["input"],
+                style![St::Width => em(6)],
+                attrs! {
+                    At::Type => "number",
+                    At::Min => 1,
+                    At::Value => model.lives_setting,
+                    At::Placeholder => lives::max_lives(""),
+                },
+                input_ev(Ev::Input, Msg::SetLives),
+            ],
+        ]),
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.continuous.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleContinuous),
+                ],
+                " continuous play: deal a new board by itself after every win",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.toddler.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleToddler),
+                ],
+                " toddler mode: big cards, no buttons on the board, hold the corner button to exit",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.picture_word_pairs.as_at_value()},
+                    ev(Ev::Change, |_| Msg::TogglePictureWordPairs),
+                ],
+                " picture and word pairs: cards with both show the picture on one tile and the word on its partner",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.list_board.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleListBoard),
+                ],
+                " list board: cards as a numbered list that works with screen readers and the arrow keys, with spoken feedback",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.speedrun_enabled.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleSpeedrun),
+                ],
+                " speedrun timer: time games to the millisecond, with a split for every pair",
+            ],
+        ],
+        div![
+            C!["field"],
+            label![
+                C!["checkbox"],
+                input![
+                    attrs! {At::Type => "checkbox", At::Checked => model.tracing.as_at_value()},
+                    ev(Ev::Change, |_| Msg::ToggleTracing),
+                ],
+                " research trace: record every flip, match and pause with a timestamp, to save as json or csv after the game",
+            ],
+        ],
+        session_settings(model),
+    ]
+}
+
+fn session_settings(model: &Model) -> Node<Msg> {
+    div![
+        C!["field"],
+        label![C!["label"], "session time limit (minutes, empty for none)"],
+        input![
+            C!["input"],
+            style![St::Width => em(6)],
+            attrs! {At::Type => "number", At::Min => 1, At::Value => model.session_minutes},
+            input_ev(Ev::Input, Msg::SetSessionMinutes),
+        ],
+        p![
+            C!["help"],
+            "the clock starts with the first game and keeps running through \"Play again!\". when it runs out the game stops and shows how the group did"
+        ],
     ]
 }
 
@@ -579,23 +5149,83 @@ fn drag_and_drop_area(model: &Model) -> Node<Msg> {
             St::Height => px(200),
             St::Width => px(200),
             St::Margin => "auto",
-            St::Background => if model.drop_zone_active { "lightgreen" } else { "lightgray" },
+            St::Background => "var(--ms-drop-zone)",
             St::FontFamily => "sans-serif",
             St::Display => "flex",
             St::FlexDirection => "column",
             St::JustifyContent => "center",
             St::AlignItems => "center",
-            St::Border => [&px(2), "dashed", "black"].join(" ");
+            St::TextAlign => "center",
+            St::Border => [&px(2), "dashed", "var(--ms-drop-zone-border)"].join(" ");
             St::BorderRadius => px(20),
         ],
+        image_queue::failures(&model.image_queue),
+        image_queue::progress(&model.image_queue),
+        div![
+            "Drop pictures (jpg/png/gif/webp), text, a deck .json or a .csv anywhere on this page"
+        ],
+        label![
+            C!["button is-small"],
+            "Import Deck or CSV...",
+            input![
+                attrs! {At::Type => "file", At::Accept => ".json,.csv"},
+                style![St::Display => "none"],
+                ev(Ev::Change, |event| {
+                    let file = event
+                        .target()?
+                        .dyn_into::<web_sys::HtmlInputElement>()
+                        .ok()?
+                        .files()?
+                        .get(0)?;
+                    import_file(&file).map(|msg| msg(file))
+                }),
+            ],
+        ],
+    ],]
+}
+
+// the whole editor page takes drops. once a drag comes in, an overlay covers the page and
+// handles the rest of it, so moving over the page's own elements doesn't end the drag
+fn drop_target(model: &Model, page: Vec<Node<Msg>>) -> Node<Msg> {
+    div![
+        // below a short page counts too
+        style![St::MinHeight => vh(100)],
         ev(Ev::DragEnter, |event| {
-            stop_and_prevent!(event);
-            Msg::DragEnter
+            let drag_event = event.into_drag_event();
+            stop_and_prevent!(drag_event);
+            let summary = drag_event
+                .data_transfer()
+                .map(|transfer| drop_zone::DragSummary::from_transfer(&transfer))
+                .unwrap_or_default();
+            Msg::DragEnter(summary)
         }),
+        page,
+        IF!(model.drop_zone_active => drop_overlay(model)),
+    ]
+}
+
+fn drop_overlay(model: &Model) -> Node<Msg> {
+    div![
+        style![
+            St::Position => "fixed",
+            St::Top => 0,
+            St::Left => 0,
+            St::Width => vw(100),
+            St::Height => vh(100),
+            St::ZIndex => 20,
+            St::Background => "var(--ms-drop-zone-active)",
+            St::Opacity => 0.9,
+            St::Display => "flex",
+            St::JustifyContent => "center",
+            St::AlignItems => "center",
+            St::Border => [&px(6), "dashed", "var(--ms-drop-zone-border)"].join(" ");
+        ],
         ev(Ev::DragOver, |event| {
             let drag_event = event.into_drag_event();
             stop_and_prevent!(drag_event);
-            drag_event.data_transfer().unwrap().set_drop_effect("copy");
+            if let Some(transfer) = drag_event.data_transfer() {
+                transfer.set_drop_effect("copy");
+            }
             Msg::DragOver
         }),
         ev(Ev::DragLeave, |event| {
@@ -605,17 +5235,19 @@ fn drag_and_drop_area(model: &Model) -> Node<Msg> {
         ev(Ev::Drop, |event| {
             let drag_event = event.into_drag_event();
             stop_and_prevent!(drag_event);
-            let file_list = drag_event.data_transfer().unwrap().files().unwrap();
-            Msg::Drop(file_list)
+            let dropped = drop_zone::Dropped::from_transfer(&drag_event.data_transfer()?);
+            Some(dropped.map_or(Msg::DragLeave, Msg::Drop))
         }),
-        div![
-            style! {
-                // we don't want to fire `DragLeave` when we are dragging over drop-zone children
-                St::PointerEvents => "none",
-            },
-            div!["Drop jpg/png/gif here"],
+        p![
+            C!["title is-4"],
+            // we don't want to fire `DragLeave` when we are dragging over the overlay's children
+            style! {St::PointerEvents => "none"},
+            model
+                .drag_summary
+                .describe()
+                .unwrap_or_else(|| "Drop pictures, text or a deck here".to_string()),
         ],
-    ],]
+    ]
 }
 
 // ------ ------
@@ -623,6 +5255,10 @@ fn drag_and_drop_area(model: &Model) -> Node<Msg> {
 // ------ ------
 #[wasm_bindgen(start)]
 pub fn start() {
+    // the thumbnail worker loads this wasm too, and has no page to mount on
+    if web_sys::window().is_none() {
+        return;
+    }
     // Mount the `app` to the element with the `id` "app".
     App::start("app", init, update, view);
 }
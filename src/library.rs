@@ -0,0 +1,318 @@
+// decks saved in the browser by name, so a teacher can keep a few around, and mix several of
+// them into one game for review days
+use crate::{deck::Deck, leaderboard, leaderboard::Leaderboards, stats::Stats, storage, Card};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use seed::{prelude::*, *};
+use std::collections::{BTreeMap, BTreeSet};
+
+// ------ ------
+//     Model
+// ------ ------
+#[derive(Default)]
+pub struct Model {
+    pub open: bool,
+    // picking a deck before the editor shows, once there are any to pick from
+    pub choosing: bool,
+    pub decks: BTreeMap<String, Deck>,
+    // name for saving the deck in the editor
+    name: String,
+    // decks picked to play together
+    selected: BTreeSet<String>,
+    // how many pairs to take from each picked deck, empty for all of them
+    pairs_each: String,
+    // the deck whose best games are showing
+    scores_for: Option<String>,
+    // the saved decks have come back from storage
+    loaded: bool,
+    // a deck from a shared link, waiting for the saved decks so it goes in with them instead of
+    // being saved over them
+    shared: Option<(String, Deck)>,
+}
+
+impl Model {
+    // the decks load in the background, see `loaded`. the picker shows once they're in, if
+    // nothing's said not to by then
+    pub fn load() -> Self {
+        Self {
+            choosing: true,
+            ..Self::default()
+        }
+    }
+
+    pub async fn load_decks() -> BTreeMap<String, Deck> {
+        storage::load_large(storage::DECKS)
+            .await
+            .unwrap_or_default()
+    }
+
+    // the name a shared deck was added under, if one was waiting
+    pub fn loaded(&mut self, decks: BTreeMap<String, Deck>) -> Option<String> {
+        self.decks = decks;
+        self.loaded = true;
+        let added = self.shared.take().map(|(name, deck)| {
+            let name = self.free_name(&name);
+            self.insert(name.clone(), deck);
+            name
+        });
+        self.choosing &= !self.decks.is_empty();
+        added
+    }
+
+    // keep a deck from a shared link with the others once they're in, the editor's list is left
+    // alone
+    pub fn add_shared(&mut self, name: String, deck: Deck) {
+        self.shared = Some((name, deck));
+    }
+
+    // `name`, or numbered after it if there's a deck called that already
+    fn free_name(&self, name: &str) -> String {
+        let name = match name.trim() {
+            "" => "Shared deck",
+            name => name,
+        };
+        (1..)
+            .map(|n| match n {
+                1 => name.to_string(),
+                n => format!("{} ({})", name, n),
+            })
+            .find(|name| !self.decks.contains_key(name))
+            .unwrap_or_default()
+    }
+
+    pub fn is_choosing(&self) -> bool {
+        self.choosing && self.loaded
+    }
+
+    // the deck that was opened, so saving goes back to it
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.trim()
+    }
+
+    pub fn pairs_each(&self) -> Option<usize> {
+        self.pairs_each
+            .trim()
+            .parse()
+            .ok()
+            .filter(|pairs| *pairs > 0)
+    }
+
+    // keep a deck under `name`, replacing any deck that already had it
+    pub fn insert(&mut self, name: String, deck: Deck) {
+        self.decks.insert(name, deck);
+        storage::save_large(storage::DECKS, &self.decks);
+    }
+
+    // the picked decks as one list of cards, sampled down if asked to
+    pub fn combined(&self, stats: &Stats) -> Vec<Card> {
+        let pairs_each = self.pairs_each();
+        self.selected
+            .iter()
+            .filter_map(|name| self.decks.get(name))
+            .flat_map(|deck| sample(&deck.cards, pairs_each, stats))
+            .collect()
+    }
+}
+
+// pick `pairs` cards, favoring the ones that have been missed the most
+pub fn sample(cards: &[Card], pairs: Option<usize>, stats: &Stats) -> Vec<Card> {
+    match pairs {
+        Some(pairs) => cards
+            .choose_multiple_weighted(&mut thread_rng(), pairs, |card| stats.weight(card.id))
+            .map(|picked| picked.cloned().collect())
+            .unwrap_or_else(|_| cards.iter().take(pairs).cloned().collect()),
+        None => cards.to_vec(),
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+pub enum Msg {
+    Toggle,
+    SetName(String),
+    Delete(String),
+    ToggleSelected(String),
+    SetPairsEach(String),
+    ShowScores(String),
+    ChooseDeck,
+    KeepEditing,
+    // handled by the app, since it owns the deck
+    Save,
+    Load(String),
+    PlaySelected,
+    NewDeck,
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Toggle => model.open = !model.open,
+        Msg::SetName(name) => model.name = name,
+        Msg::Delete(name) => {
+            model.decks.remove(&name);
+            model.selected.remove(&name);
+            storage::save_large(storage::DECKS, &model.decks);
+            // nothing left to pick from
+            model.choosing &= !model.decks.is_empty();
+        }
+        Msg::ToggleSelected(name) => {
+            if !model.selected.remove(&name) {
+                model.selected.insert(name);
+            }
+        }
+        Msg::SetPairsEach(pairs) => model.pairs_each = pairs,
+        Msg::ShowScores(name) => {
+            model.scores_for = if model.scores_for.as_ref() == Some(&name) {
+                None
+            } else {
+                Some(name)
+            };
+        }
+        Msg::ChooseDeck => model.choosing = true,
+        Msg::KeepEditing => model.choosing = false,
+        Msg::Save | Msg::Load(_) | Msg::PlaySelected | Msg::NewDeck => (),
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn view(model: &Model, deck_is_empty: bool, leaderboards: &Leaderboards) -> Node<Msg> {
+    if !model.open {
+        return empty![];
+    }
+
+    let rows = model.decks.iter().map(|(name, deck)| {
+        let (select, load, delete, scores) =
+            (name.clone(), name.clone(), name.clone(), name.clone());
+        tr![
+            td![input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => model.selected.contains(name).as_at_value(),
+                },
+                ev(Ev::Change, move |_| Msg::ToggleSelected(select)),
+            ]],
+            td![name],
+            td![format!("{} cards", deck.cards.len())],
+            td![
+                button![
+                    "Open",
+                    C!["button is-small"],
+                    ev(Ev::Click, move |_| Msg::Load(load)),
+                ],
+                button![
+                    "Best Games",
+                    C!["button is-small is-info is-light"],
+                    ev(Ev::Click, move |_| Msg::ShowScores(scores)),
+                ],
+                button![
+                    "Delete",
+                    C!["button is-small is-danger"],
+                    ev(Ev::Click, move |_| Msg::Delete(delete)),
+                ],
+            ],
+        ]
+    });
+
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "Saved decks"],
+        div![
+            C!["field has-addons"],
+            div![
+                C!["control is-expanded"],
+                input![
+                    C!["input"],
+                    attrs! {At::Value => model.name, At::Placeholder => "name for this deck"},
+                    input_ev(Ev::Input, Msg::SetName),
+                ],
+            ],
+            div![
+                C!["control"],
+                button![
+                    "Save Deck",
+                    C!["button is-success"],
+                    attrs! {At::Disabled => (model.name().is_empty() || deck_is_empty).as_at_value()},
+                    ev(Ev::Click, |_| Msg::Save),
+                ],
+            ],
+        ],
+        if model.decks.is_empty() {
+            p!["no saved decks yet"]
+        } else {
+            table![C!["table is-fullwidth"], tbody![rows]]
+        },
+        model.scores_for.as_ref().map(|name| leaderboard::view(
+            &leaderboard::title(name),
+            leaderboards.top(name),
+            None
+        )),
+        IF!(!model.selected.is_empty() => div![
+            C!["field is-grouped"],
+            label![C!["label"], "pairs from each deck (empty for all)"],
+            input![
+                C!["input is-small"],
+                style![St::Width => em(5)],
+                attrs! {At::Type => "number", At::Min => 1, At::Value => model.pairs_each},
+                input_ev(Ev::Input, Msg::SetPairsEach),
+            ],
+            button![
+                format!("Play {} Decks Together", model.selected.len()),
+                C!["button is-success"],
+                ev(Ev::Click, |_| Msg::PlaySelected),
+            ],
+        ]),
+    ]
+}
+
+// the page before the editor: which deck is this lesson's
+pub fn manager_view(model: &Model, editing_cards: usize) -> Node<Msg> {
+    let decks = model.decks.iter().map(|(name, deck)| {
+        let (load, delete) = (name.clone(), name.clone());
+        div![
+            C!["column is-one-third"],
+            div![
+                C!["box"],
+                p![C!["title is-5"], name],
+                p![C!["subtitle is-6"], format!("{} cards", deck.cards.len())],
+                div![
+                    C!["buttons"],
+                    button![
+                        "Open",
+                        C!["button is-success"],
+                        ev(Ev::Click, move |_| Msg::Load(load)),
+                    ],
+                    button![
+                        "Delete",
+                        C!["button is-danger is-light"],
+                        ev(Ev::Click, move |_| Msg::Delete(delete)),
+                    ],
+                ],
+            ],
+        ]
+    });
+
+    div![
+        C!["section"],
+        h1![C!["title is-3"], "Pick a deck"],
+        div![C!["columns is-multiline"], decks],
+        div![
+            C!["buttons"],
+            button![
+                "New Deck",
+                C!["button is-large is-link"],
+                ev(Ev::Click, |_| Msg::NewDeck),
+            ],
+            IF!(editing_cards > 0 => button![
+                format!("Keep Editing the Current List ({} cards)", editing_cards),
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::KeepEditing),
+            ]),
+        ],
+    ]
+}
@@ -0,0 +1,126 @@
+// the board as a numbered list instead of a grid, made for screen readers: every card is a
+// button that says what it is, the arrow keys move between cards, and what happened after
+// each click is announced (and read out loud)
+use crate::{game::Outcome, CardState, Msg, PairHalf, PlayedCard};
+use seed::{prelude::*, *};
+
+const TILE_ATTRIBUTE: &str = "data-list-tile";
+
+// what the tile shows, as words
+fn face(tile: &PlayedCard) -> String {
+    let text = match (tile.half, &tile.card.sound) {
+        (PairHalf::B, Some(sound)) => Some(sound.as_str()),
+        (PairHalf::B, None) => tile.card.text_b.as_deref(),
+        (PairHalf::A, _) => tile.card.text.as_deref(),
+    };
+    match (text, &tile.card.photo) {
+        (Some(text), _) if !text.trim().is_empty() => text.to_string(),
+        (_, Some(_)) => "a picture".to_string(),
+        _ => "blank".to_string(),
+    }
+}
+
+// (the grid's tiles say the same to a screen reader)
+pub fn label(tile: &PlayedCard, index: usize) -> String {
+    if tile.matched {
+        format!("card {}: {}, matched", index + 1, face(tile))
+    } else if tile.displayed == CardState::FaceUp {
+        format!("card {}: {}, face up", index + 1, face(tile))
+    } else {
+        format!("card {}, face down", index + 1)
+    }
+}
+
+// what to say after a click
+pub fn announce(outcome: &Outcome, index: usize, board: &[PlayedCard]) -> Option<String> {
+    let tile = board.get(index)?;
+    let found = board.iter().filter(|tile| tile.matched).count() / 2;
+    let pairs = board.len() / 2;
+    Some(match outcome {
+        Outcome::Ignored => return None,
+        Outcome::Reset => "cards turned back over".to_string(),
+        Outcome::Flipped => format!("card {}: {}", index + 1, face(tile)),
+        Outcome::Matched if found == pairs => {
+            format!("{}. match! every pair found, well done!", face(tile))
+        }
+        Outcome::Matched => format!("{}. match! {} of {} pairs found", face(tile), found, pairs),
+        Outcome::Missed => format!(
+            "card {}: {}. not a match, pick any card to turn them back over",
+            index + 1,
+            face(tile)
+        ),
+    })
+}
+
+// arrow keys, home and end move between the cards
+fn move_focus(event: &web_sys::Event) {
+    let Some(key) = event
+        .dyn_ref::<web_sys::KeyboardEvent>()
+        .map(web_sys::KeyboardEvent::key)
+    else {
+        return;
+    };
+    let Some(current) = event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|element| element.get_attribute(TILE_ATTRIBUTE))
+        .and_then(|index| index.parse::<u32>().ok())
+    else {
+        return;
+    };
+    let next = match key.as_str() {
+        "ArrowDown" | "ArrowRight" => current + 1,
+        "ArrowUp" | "ArrowLeft" => current.saturating_sub(1),
+        "Home" => 0,
+        "End" => u32::MAX,
+        _ => return,
+    };
+    let buttons = seed::document().query_selector_all(&format!("[{}]", TILE_ATTRIBUTE));
+    if let Ok(buttons) = buttons {
+        let next = next.min(buttons.length().saturating_sub(1));
+        if let Some(button) = buttons
+            .get(next)
+            .and_then(|button| button.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            event.prevent_default();
+            let _garbage = button.focus();
+        }
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn view(board: &[PlayedCard], announcement: &str) -> Node<Msg> {
+    div![
+        // read out by screen readers whenever it changes
+        p![
+            C!["title is-5"],
+            attrs! {At::from("role") => "status", At::from("aria-live") => "assertive"},
+            announcement,
+        ],
+        ol![
+            attrs! {At::from("aria-label") => "cards"},
+            style![St::ListStyle => "none", St::Padding => 0],
+            board.iter().enumerate().map(|(index, tile)| {
+                let face_up = tile.displayed == CardState::FaceUp || tile.matched;
+                li![button![
+                    C![
+                        "button is-large is-fullwidth",
+                        IF!(tile.matched => "is-success is-light"),
+                        IF!(face_up && !tile.matched => "is-info"),
+                    ],
+                    style![St::JustifyContent => "flex-start", St::MarginBottom => rem(0.25)],
+                    attrs! {At::from(TILE_ATTRIBUTE) => index},
+                    label(tile, index),
+                    ev(Ev::Click, move |_| if face_up {
+                        Msg::ResetClick
+                    } else {
+                        Msg::GuessCard(index)
+                    }),
+                ]]
+            }),
+            ev(Ev::KeyDown, |event| move_focus(&event)),
+        ],
+    ]
+}
@@ -0,0 +1,78 @@
+// lives mode: only so many wrong guesses are allowed, running out ends the game with whatever
+// pairs were found so far
+use crate::{Msg, PlayedCard};
+use seed::{prelude::*, *};
+
+const DEFAULT_LIVES: u32 = 5;
+
+pub struct Lives {
+    left: u32,
+    max: u32,
+}
+
+impl Lives {
+    pub fn new(max: u32) -> Self {
+        Self { left: max, max }
+    }
+
+    pub fn lose_one(&mut self) {
+        self.left = self.left.saturating_sub(1);
+    }
+
+    pub fn is_out(&self) -> bool {
+        self.left == 0
+    }
+}
+
+// parse the lives setting, empty means the default
+pub fn max_lives(setting: &str) -> u32 {
+    setting
+        .trim()
+        .parse()
+        .ok()
+        .filter(|lives| *lives > 0)
+        .unwrap_or(DEFAULT_LIVES)
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn hud(lives: &Lives) -> Node<Msg> {
+    let hearts: String = (0..lives.max)
+        .map(|heart| if heart < lives.left { "❤️" } else { "🤍" })
+        .collect();
+    p![
+        C!["title is-4"],
+        attrs! {At::Title => format!("{} lives left", lives.left)},
+        hearts
+    ]
+}
+
+// shown instead of the board once the last life is gone
+pub fn game_over(board: &[PlayedCard]) -> Node<Msg> {
+    let found = board.iter().filter(|tile| tile.matched).count() / 2;
+    let pairs = board.len() / 2;
+    div![
+        C!["box"],
+        style![St::TextAlign => "center"],
+        h1![C!["title is-4"], "💔 Out of lives!"],
+        p![
+            C!["title is-5"],
+            format!("You found {} of {} pairs", found, pairs)
+        ],
+        div![
+            C!["buttons"],
+            style![St::JustifyContent => "center"],
+            button![
+                "Try Again",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::StartGame),
+            ],
+            button![
+                "Back to Editor",
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::EditDeck),
+            ],
+        ],
+    ]
+}
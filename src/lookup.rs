@@ -0,0 +1,191 @@
+// look words up in a user configured dictionary or translation api
+//
+// the api is a url template: {word} is replaced with the card text and {lang} with the deck's
+// language (like "en"). the answer can be plain text or json from one of the common free apis
+use crate::Msg;
+use seed::{prelude::*, *};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use ulid::Ulid;
+
+// ready made templates for the settings panel
+pub const PRESETS: [(&str, &str); 2] = [
+    (
+        "dictionary (dictionaryapi.dev)",
+        "https://api.dictionaryapi.dev/api/v2/entries/{lang}/{word}",
+    ),
+    (
+        "translate to spanish (MyMemory)",
+        "https://api.mymemory.translated.net/get?q={word}&langpair={lang}|es",
+    ),
+];
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LookupSettings {
+    pub url_template: String,
+}
+
+impl LookupSettings {
+    pub fn is_configured(&self) -> bool {
+        self.url_template.contains("{word}")
+    }
+
+    fn url_for(&self, word: &str, lang: &str) -> String {
+        let lang = lang.split(['-', '_']).next().unwrap_or(lang);
+        self.url_template
+            .trim()
+            .replace(
+                "{word}",
+                &String::from(js_sys::encode_uri_component(word.trim())),
+            )
+            .replace("{lang}", &String::from(js_sys::encode_uri_component(lang)))
+    }
+}
+
+// fetch a definition or translation for `word`
+pub async fn suggest(
+    settings: LookupSettings,
+    lang: String,
+    word: String,
+) -> Result<String, String> {
+    let response = fetch(settings.url_for(&word, &lang))
+        .await
+        .and_then(Response::check_status)
+        .map_err(|error| format!("lookup failed: {:?}", error))?;
+    let body = response
+        .text()
+        .await
+        .map_err(|error| format!("lookup failed: {:?}", error))?;
+
+    let suggestion = match serde_json::from_str::<Value>(&body) {
+        Ok(json) => find_suggestion(&json),
+        // not json, so it's probably the answer as plain text
+        Err(_) => Some(body.trim().to_string()),
+    };
+
+    suggestion
+        .filter(|text| !text.is_empty())
+        .ok_or_else(|| format!("nothing found for \"{}\"", word))
+}
+
+// dig the useful text out of the json the common apis send back
+fn find_suggestion(json: &Value) -> Option<String> {
+    let pointers = [
+        // dictionaryapi.dev
+        "/0/meanings/0/definitions/0/definition",
+        // MyMemory
+        "/responseData/translatedText",
+        // LibreTranslate
+        "/translatedText",
+        // DeepL
+        "/translations/0/text",
+        // anything simple
+        "/translation",
+        "/definition",
+        "/text",
+    ];
+    pointers
+        .iter()
+        .find_map(|pointer| json.pointer(pointer))
+        .or(Some(json))
+        .and_then(Value::as_str)
+        .map(|text| text.trim().to_string())
+}
+
+// ------ ------
+//  Batch lookups
+// ------ ------
+
+// looking up every card at once, kept around so the teacher can review before anything changes
+#[derive(Default)]
+pub struct BatchLookup {
+    pub rows: BTreeMap<Ulid, BatchRow>,
+}
+
+pub struct BatchRow {
+    pub word: String,
+    // what the card has now, so the review shows what would be replaced
+    pub current: Option<String>,
+    // None while the request is still out
+    pub result: Option<Result<String, String>>,
+    pub keep: bool,
+}
+
+impl BatchLookup {
+    pub fn waiting(&self) -> usize {
+        self.rows
+            .values()
+            .filter(|row| row.result.is_none())
+            .count()
+    }
+
+    // the suggestions that should be written to the cards
+    pub fn accepted(&self) -> impl Iterator<Item = (Ulid, &str)> {
+        self.rows.iter().filter_map(|(id, row)| match &row.result {
+            Some(Ok(text)) if row.keep && !text.is_empty() => Some((*id, text.as_str())),
+            _ => None,
+        })
+    }
+}
+
+pub fn review_view(batch: &BatchLookup) -> Node<Msg> {
+    let waiting = batch.waiting();
+
+    let rows = batch.rows.iter().map(|(id, row)| {
+        let id = *id;
+        let suggestion = match &row.result {
+            None => span!["looking up..."],
+            Some(Err(error)) => span![C!["has-text-danger"], error],
+            Some(Ok(text)) => input![
+                C!["input is-small"],
+                attrs! {At::Value => text},
+                input_ev(Ev::Input, move |text| Msg::EditBatchSuggestion { id, text }),
+            ],
+        };
+        tr![
+            td![input![
+                attrs! {
+                    At::Type => "checkbox",
+                    At::Checked => row.keep.as_at_value(),
+                    At::Disabled => (!matches!(row.result, Some(Ok(_)))).as_at_value(),
+                },
+                ev(Ev::Change, move |_| Msg::ToggleBatchRow(id)),
+            ]],
+            td![&row.word],
+            td![suggestion],
+            td![C!["has-text-grey"], row.current.as_deref().unwrap_or("")],
+        ]
+    });
+
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "Review matching text"],
+        IF!(waiting > 0 => p![format!("waiting for {} more...", waiting)]),
+        table![
+            C!["table is-fullwidth"],
+            thead![tr![
+                th![],
+                th!["card"],
+                th!["new matching text"],
+                th!["now"]
+            ]],
+            tbody![rows],
+        ],
+        div![
+            C!["buttons"],
+            button![
+                "Use checked",
+                C!["button is-success"],
+                attrs! {At::Disabled => (waiting > 0).as_at_value()},
+                ev(Ev::Click, |_| Msg::ApplyBatchLookup),
+            ],
+            button![
+                "Cancel",
+                C!["button"],
+                ev(Ev::Click, |_| Msg::CancelBatchLookup),
+            ],
+        ],
+    ]
+}
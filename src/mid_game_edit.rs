@@ -0,0 +1,72 @@
+// fixing a card in the middle of a game (usually a typo, sometimes a better photo) without
+// leaving it. the game waits behind this page and the board picks up the changes in place
+use crate::{dealt_card, photo_edit, Card, Msg, PairHalf, PlayedCard};
+use seed::{prelude::*, *};
+use std::collections::BTreeMap;
+use ulid::Ulid;
+
+// put the edited cards on the board, keeping where they are and whether they're found
+pub fn refresh(board: &mut [PlayedCard], words: &BTreeMap<Ulid, Card>, picture_word_pairs: bool) {
+    for tile in board {
+        // a card deleted from the deck stays on this board until the game is over
+        if let Some(card) = words.get(&tile.card.id) {
+            tile.card = dealt_card(card, picture_word_pairs);
+        }
+    }
+}
+
+// the cards on the board, each once, in the order they're dealt
+pub fn view(
+    board: &[PlayedCard],
+    words: &BTreeMap<Ulid, Card>,
+    errors: &BTreeMap<Ulid, String>,
+) -> Node<Msg> {
+    let rows = board
+        .iter()
+        .filter(|tile| tile.half == PairHalf::A)
+        .filter_map(|tile| words.get(&tile.card.id))
+        .map(|card| {
+            let id = card.id;
+            tr![
+                td![card.photo.as_ref().map(|photo| img![
+                    attrs! {At::Src => photo, At::Alt => card.text.as_deref().unwrap_or("")},
+                    style![St::MaxWidth => px(80), St::MaxHeight => px(80)],
+                ])],
+                td![
+                    input![
+                        C!["input"],
+                        attrs! {
+                            At::Value => card.text.as_deref().unwrap_or(""),
+                            At::Placeholder => "text",
+                        },
+                        input_ev(Ev::Input, move |text| Msg::UpdateCardText { id, text }),
+                    ],
+                    input![
+                        C!["input is-small"],
+                        attrs! {
+                            At::Value => card.text_b.as_deref().unwrap_or(""),
+                            At::Placeholder => "partner tile's text (optional)",
+                        },
+                        input_ev(Ev::Input, move |text| Msg::UpdateCardTextB { id, text }),
+                    ],
+                    errors.get(&id).map(|error| p![C!["help is-danger"], error]),
+                ],
+                td![photo_edit::picker(id, "📷 New photo...")],
+            ]
+        });
+
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "Fix a card"],
+        p![
+            C!["subtitle is-6"],
+            "the game is paused, found pairs and face up cards stay as they are"
+        ],
+        table![C!["table is-fullwidth"], tbody![rows]],
+        button![
+            "Back to the game",
+            C!["button is-large is-success"],
+            ev(Ev::Click, |_| Msg::BackToGame),
+        ],
+    ]
+}
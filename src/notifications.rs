@@ -0,0 +1,97 @@
+// little messages stacked in the corner: imports that failed, decks saved and copied, things that
+// happened in a game. each goes away by itself after a while, or with its x
+use crate::Msg;
+use seed::{prelude::*, *};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Level {
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+impl Level {
+    const fn class(self) -> &'static str {
+        match self {
+            Self::Info => "is-info",
+            Self::Success => "is-success",
+            Self::Warning => "is-warning",
+            Self::Danger => "is-danger",
+        }
+    }
+
+    // errors stay up longer, there's more to read and they matter more
+    const fn shown_ms(self) -> u32 {
+        match self {
+            Self::Info | Self::Success => 4000,
+            Self::Warning | Self::Danger => 10000,
+        }
+    }
+}
+
+pub struct Notification {
+    id: u32,
+    level: Level,
+    text: String,
+}
+
+#[derive(Default)]
+pub struct Notifications {
+    shown: Vec<Notification>,
+    next_id: u32,
+}
+
+impl Notifications {
+    // show a message, and take it down again once its time is up
+    pub fn add(&mut self, level: Level, text: impl Into<String>, orders: &mut impl Orders<Msg>) {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.shown.push(Notification {
+            id,
+            level,
+            text: text.into(),
+        });
+        orders.perform_cmd(cmds::timeout(level.shown_ms(), move || {
+            Msg::DismissNotification(id)
+        }));
+    }
+
+    pub fn dismiss(&mut self, id: u32) {
+        self.shown.retain(|notification| notification.id != id);
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn view(notifications: &Notifications) -> Node<Msg> {
+    div![
+        style![
+            St::Position => "fixed",
+            St::Top => rem(1),
+            St::Right => rem(1),
+            St::ZIndex => 40,
+            St::MaxWidth => rem(24),
+        ],
+        attrs! {At::from("aria-live") => "polite"},
+        notifications.shown.iter().map(|notification| {
+            let id = notification.id;
+            let role = if notification.level == Level::Danger {
+                "alert"
+            } else {
+                "status"
+            };
+            div![
+                C!["notification", notification.level.class()],
+                attrs! {At::from("role") => role},
+                button![
+                    C!["delete"],
+                    attrs! {At::AriaLabel => "dismiss"},
+                    ev(Ev::Click, move |_| Msg::DismissNotification(id)),
+                ],
+                &notification.text,
+            ]
+        }),
+    ]
+}
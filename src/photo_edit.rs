@@ -0,0 +1,256 @@
+// fixing a card's picture after it's been added: turning a sideways phone photo the right way up
+// and trimming off its edges. the dialog previews it with css, applying it draws it on a canvas
+use crate::{thumbnail, Msg};
+use seed::{prelude::*, *};
+use std::f64::consts::FRAC_PI_2;
+use ulid::Ulid;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement};
+
+// trimming can take this much of the picture off each edge, there has to be some left
+const MAX_TRIM: u8 = 40;
+
+#[derive(Clone, Copy)]
+pub enum Edge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Edge {
+    const ALL: [Self; 4] = [Self::Top, Self::Right, Self::Bottom, Self::Left];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Right => "right",
+            Self::Bottom => "bottom",
+            Self::Left => "left",
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct PhotoEdit {
+    pub id: Ulid,
+    photo: String,
+    // quarter turns clockwise
+    turns: u16,
+    // percent off each edge of the turned picture, the way it's seen: top, right, bottom, left
+    trim: [u8; 4],
+}
+
+impl PhotoEdit {
+    pub fn new(id: Ulid, photo: String) -> Self {
+        Self {
+            id,
+            photo,
+            turns: 0,
+            trim: [0; 4],
+        }
+    }
+
+    pub fn turn(&mut self, clockwise: bool) {
+        self.turns = (self.turns + if clockwise { 1 } else { 3 }) % 4;
+    }
+
+    pub fn set_trim(&mut self, edge: Edge, percent: &str) {
+        if let Ok(percent) = percent.parse::<u8>() {
+            self.trim[edge as usize] = percent.min(MAX_TRIM);
+        }
+    }
+
+    // the trims on the picture's own edges, before it's turned. after a quarter turn clockwise
+    // its left edge is the one on top
+    fn unturned_trim(&self) -> [f64; 4] {
+        let mut trim = [0.0; 4];
+        for (edge, unturned) in trim.iter_mut().enumerate() {
+            *unturned = f64::from(self.trim[(edge + usize::from(self.turns)) % 4]) / 100.0;
+        }
+        trim
+    }
+
+    // the fixed picture as a thumbnail
+    pub async fn apply(self) -> Result<String, String> {
+        let failed = |_| "couldn't fix the picture".to_string();
+        let image: HtmlImageElement = seed::document()
+            .create_element("img")
+            .map_err(failed)?
+            .unchecked_into();
+        image.set_src(&self.photo);
+        wasm_bindgen_futures::JsFuture::from(image.decode())
+            .await
+            .map_err(failed)?;
+
+        let (width, height) = (
+            f64::from(image.natural_width()),
+            f64::from(image.natural_height()),
+        );
+        let [top, right, bottom, left] = self.unturned_trim();
+        let (x, y) = (width * left, height * top);
+        let (kept_width, kept_height) =
+            (width * (1.0 - left - right), height * (1.0 - top - bottom));
+        let sideways = self.turns % 2 == 1;
+        let (canvas_width, canvas_height) = if sideways {
+            (kept_height, kept_width)
+        } else {
+            (kept_width, kept_height)
+        };
+
+        let canvas: HtmlCanvasElement = seed::document()
+            .create_element("canvas")
+            .map_err(failed)?
+            .unchecked_into();
+        // whole pixels, a thumbnail is a few hundred across at most
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        {
+            canvas.set_width(canvas_width.round() as u32);
+            canvas.set_height(canvas_height.round() as u32);
+        }
+        let context: CanvasRenderingContext2d = canvas
+            .get_context("2d")
+            .map_err(failed)?
+            .ok_or("couldn't fix the picture")?
+            .unchecked_into();
+        // turn around the middle, then draw the kept part centered on it
+        context
+            .translate(canvas_width / 2.0, canvas_height / 2.0)
+            .map_err(failed)?;
+        context
+            .rotate(f64::from(self.turns) * FRAC_PI_2)
+            .map_err(failed)?;
+        context
+            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                &image,
+                x,
+                y,
+                kept_width,
+                kept_height,
+                -kept_width / 2.0,
+                -kept_height / 2.0,
+                kept_width,
+                kept_height,
+            )
+            .map_err(failed)?;
+
+        // the same kind of picture it was, canvases can't write gifs so those come out as pngs
+        let kind = self
+            .photo
+            .split(';')
+            .next()
+            .and_then(|kind| kind.strip_prefix("data:"))
+            .unwrap_or("image/png");
+        let fixed = canvas.to_data_url_with_type(kind).map_err(failed)?;
+        let bytes = fixed
+            .split_once(";base64,")
+            .and_then(|(_, b64)| base64::decode(b64).ok())
+            .ok_or("couldn't fix the picture")?;
+        thumbnail::data_url(&bytes).map_err(|error| error.to_string())
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+// a button that picks a new picture for the card from the device
+pub fn picker(id: Ulid, text: &str) -> Node<Msg> {
+    label![
+        C!["button is-small"],
+        text,
+        input![
+            attrs! {At::Type => "file", At::Accept => "image/*"},
+            style![St::Display => "none"],
+            ev(Ev::Change, move |event| {
+                let file = event
+                    .target()?
+                    .dyn_into::<web_sys::HtmlInputElement>()
+                    .ok()?
+                    .files()?
+                    .get(0)?;
+                Some(Msg::ReplacePhoto { id, file })
+            }),
+        ],
+    ]
+}
+
+pub fn view(edit: &PhotoEdit) -> Node<Msg> {
+    let [top, right, bottom, left] = edit.unturned_trim().map(|trim| trim * 100.0);
+    div![
+        C!["modal is-active"],
+        div![
+            C!["modal-background"],
+            ev(Ev::Click, |_| Msg::CancelPhotoEdit)
+        ],
+        div![
+            C!["modal-content box"],
+            h2![C!["title is-4"], "Fix the picture"],
+            div![
+                style![
+                    St::Display => "flex",
+                    St::JustifyContent => "center",
+                    St::AlignItems => "center",
+                    St::Height => px(thumbnail::THUMB_SIZE + 50),
+                ],
+                img![
+                    attrs! {At::Src => edit.photo, At::Alt => "the card's picture"},
+                    style![
+                        St::MaxWidth => px(thumbnail::THUMB_SIZE),
+                        St::MaxHeight => px(thumbnail::THUMB_SIZE),
+                        St::Transform => format!("rotate({}deg)", edit.turns * 90),
+                        St::ClipPath => format!(
+                            "inset({}% {}% {}% {}%)",
+                            top, right, bottom, left
+                        ),
+                    ],
+                ],
+            ],
+            div![
+                C!["buttons"],
+                style![St::JustifyContent => "center"],
+                button![
+                    "↺ Turn left",
+                    C!["button"],
+                    ev(Ev::Click, |_| Msg::TurnPhoto(false)),
+                ],
+                button![
+                    "↻ Turn right",
+                    C!["button"],
+                    ev(Ev::Click, |_| Msg::TurnPhoto(true)),
+                ],
+            ],
+            Edge::ALL.iter().map(|edge| {
+                let edge = *edge;
+                div![
+                    C!["field"],
+                    label![
+                        C!["label is-small"],
+                        format!("trim the {}", edge.name()),
+                        input![
+                            attrs! {
+                                At::Type => "range",
+                                At::Min => 0,
+                                At::Max => MAX_TRIM,
+                                At::Value => edit.trim[edge as usize],
+                            },
+                            style![St::Width => percent(100)],
+                            input_ev(Ev::Input, move |percent| Msg::TrimPhoto(edge, percent)),
+                        ],
+                    ],
+                ]
+            }),
+            div![
+                C!["buttons"],
+                button![
+                    "Save Picture",
+                    C!["button is-success"],
+                    ev(Ev::Click, |_| Msg::ApplyPhotoEdit),
+                ],
+                button![
+                    "Cancel",
+                    C!["button"],
+                    ev(Ev::Click, |_| Msg::CancelPhotoEdit),
+                ],
+            ],
+        ],
+    ]
+}
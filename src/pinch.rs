@@ -0,0 +1,235 @@
+// pinch to zoom and drag to pan the board on touch screens, so a 30 pair board is still usable
+// on a tablet. it's done here instead of by the browser so taps keep landing on the right card
+use seed::{prelude::*, *};
+
+const MAX_SCALE: f64 = 4.0;
+// fingers that moved less than this (in px) still count as a tap
+const TAP_SLOP: f64 = 10.0;
+
+#[derive(Clone, Copy, Default)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Point {
+    fn distance(self, other: Self) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    fn middle(self, other: Self) -> Self {
+        Self {
+            x: (self.x + other.x) / 2.,
+            y: (self.y + other.y) / 2.,
+        }
+    }
+}
+
+// where the fingers are, relative to the board's corner, and how big the board is
+pub struct Touches {
+    points: Vec<Point>,
+    size: Point,
+}
+
+enum Gesture {
+    Pan {
+        start: Point,
+        offset: Point,
+    },
+    Pinch {
+        distance: f64,
+        middle: Point,
+        scale: f64,
+        offset: Point,
+    },
+}
+
+// ------ ------
+//     Model
+// ------ ------
+pub struct Model {
+    scale: f64,
+    offset: Point,
+    size: Point,
+    gesture: Option<Gesture>,
+    // the fingers went far enough that letting go isn't a tap
+    moved: bool,
+}
+
+impl Default for Model {
+    fn default() -> Self {
+        Self {
+            scale: 1.,
+            offset: Point::default(),
+            size: Point::default(),
+            gesture: None,
+            moved: false,
+        }
+    }
+}
+
+impl Model {
+    fn is_zoomed(&self) -> bool {
+        self.scale > 1.
+    }
+
+    // start over from whatever fingers are still down
+    fn begin(&mut self, points: &[Point]) {
+        self.gesture = match points {
+            [one, two, ..] => Some(Gesture::Pinch {
+                distance: one.distance(*two),
+                middle: one.middle(*two),
+                scale: self.scale,
+                offset: self.offset,
+            }),
+            [one] => Some(Gesture::Pan {
+                start: *one,
+                offset: self.offset,
+            }),
+            [] => None,
+        };
+    }
+
+    // don't let the board slide out of view
+    fn clamp_offset(&mut self) {
+        let min_x = self.size.x * (1. - self.scale);
+        let min_y = self.size.y * (1. - self.scale);
+        self.offset.x = self.offset.x.clamp(min_x.min(0.), 0.);
+        self.offset.y = self.offset.y.clamp(min_y.min(0.), 0.);
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+pub enum Msg {
+    Start(Touches),
+    Move(Touches),
+    End(Touches),
+    Reset,
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Start(touches) => {
+            if model.gesture.is_none() {
+                model.moved = false;
+            }
+            model.size = touches.size;
+            model.begin(&touches.points);
+        }
+        Msg::Move(touches) => match (&model.gesture, touches.points.as_slice()) {
+            (
+                Some(Gesture::Pinch {
+                    distance,
+                    middle,
+                    scale,
+                    offset,
+                }),
+                [one, two, ..],
+            ) => {
+                model.moved = true;
+                if *distance > 0. {
+                    model.scale = (scale * one.distance(*two) / distance).clamp(1., MAX_SCALE);
+                }
+                // keep the spot between the fingers under the fingers
+                let now = one.middle(*two);
+                let grown = model.scale / scale;
+                model.offset = Point {
+                    x: now.x - (middle.x - offset.x) * grown,
+                    y: now.y - (middle.y - offset.y) * grown,
+                };
+                model.clamp_offset();
+            }
+            (Some(Gesture::Pan { start, offset }), [one, ..]) => {
+                if one.distance(*start) > TAP_SLOP {
+                    model.moved = true;
+                }
+                model.offset = Point {
+                    x: offset.x + one.x - start.x,
+                    y: offset.y + one.y - start.y,
+                };
+                model.clamp_offset();
+            }
+            _ => (),
+        },
+        Msg::End(touches) => model.begin(&touches.points),
+        Msg::Reset => *model = Model::default(),
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+fn touches(event: &web_sys::TouchEvent) -> Touches {
+    let rect = event
+        .current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .map(|board| board.get_bounding_client_rect());
+    let (left, top) = rect
+        .as_ref()
+        .map_or((0., 0.), |rect| (rect.left(), rect.top()));
+    let list = event.touches();
+    Touches {
+        points: (0..list.length())
+            .filter_map(|index| list.get(index))
+            .map(|touch| Point {
+                x: f64::from(touch.client_x()) - left,
+                y: f64::from(touch.client_y()) - top,
+            })
+            .collect(),
+        size: rect.map_or_else(Point::default, |rect| Point {
+            x: rect.width(),
+            y: rect.height(),
+        }),
+    }
+}
+
+// the board, zoomed and panned. `wrap` turns this module's messages into the app's
+pub fn view<Ms: 'static>(model: &Model, wrap: fn(Msg) -> Ms, board: Node<Ms>) -> Node<Ms> {
+    let zoomed = model.is_zoomed();
+    let moved = model.moved;
+    div![
+        style![
+            St::Overflow => "hidden",
+            St::Position => "relative",
+            // one finger still scrolls the page until the board is zoomed in
+            St::TouchAction => if zoomed { "none" } else { "pan-y" },
+        ],
+        touch_ev(Ev::TouchStart, move |event| wrap(Msg::Start(touches(
+            &event
+        )))),
+        touch_ev(Ev::TouchMove, move |event| {
+            if zoomed || event.touches().length() > 1 {
+                event.prevent_default();
+            }
+            wrap(Msg::Move(touches(&event)))
+        }),
+        // a pinch or a drag shouldn't end up flipping whatever card was under the finger
+        touch_ev(Ev::TouchEnd, move |event| {
+            if moved {
+                event.prevent_default();
+            }
+            wrap(Msg::End(touches(&event)))
+        }),
+        touch_ev(Ev::TouchCancel, move |event| wrap(Msg::End(touches(
+            &event
+        )))),
+        div![
+            style![
+                St::TransformOrigin => "0 0",
+                St::Transform => format!(
+                    "translate({}px, {}px) scale({})",
+                    model.offset.x, model.offset.y, model.scale
+                ),
+            ],
+            board,
+        ],
+        IF!(zoomed => button![
+            "Zoom out",
+            C!["button is-small"],
+            style![St::Position => "absolute", St::Top => px(4), St::Right => px(4)],
+            ev(Ev::Click, move |_| wrap(Msg::Reset)),
+        ]),
+    ]
+}
@@ -0,0 +1,28 @@
+// decode every picture on the board before showing it, so the first flip of each card doesn't
+// stutter on a slow tablet while the browser decodes its image
+use seed::{prelude::*, JsFuture};
+use std::collections::BTreeSet;
+use web_sys::HtmlImageElement;
+
+// start decoding all of `sources` at once and wait for them. the images are handed back so they
+// can be kept around, which keeps the decoded pictures in the browser's cache
+pub async fn decode_all(sources: BTreeSet<String>) -> Vec<HtmlImageElement> {
+    let images: Vec<(HtmlImageElement, js_sys::Promise)> = sources
+        .iter()
+        .filter_map(|source| {
+            let image = HtmlImageElement::new().ok()?;
+            image.set_decoding("sync");
+            image.set_src(source);
+            let decoded = image.decode();
+            Some((image, decoded))
+        })
+        .collect();
+
+    let mut decoded = Vec::with_capacity(images.len());
+    for (image, promise) in images {
+        // a picture that can't be decoded just shows up the slow way
+        let _garbage = JsFuture::from(promise).await;
+        decoded.push(image);
+    }
+    decoded
+}
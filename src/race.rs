@@ -0,0 +1,160 @@
+// two players racing on copies of the same board, side by side. the left board is the app's
+// usual one, the right one lives here, and clicks on it are played with the same rules by
+// swapping it in for a moment
+use crate::{board_click, board_rows, game, print_card, CardState, Model, Msg, PlayedCard};
+use seed::{prelude::*, *};
+use std::collections::BTreeSet;
+use ulid::Ulid;
+
+// each board only gets half the screen
+const COLUMNS: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Player {
+    Left,
+    Right,
+}
+
+impl Player {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Left => "Player 1",
+            Self::Right => "Player 2",
+        }
+    }
+}
+
+pub struct Race {
+    board: Vec<PlayedCard>,
+    last: Option<Ulid>,
+    needs_reset: bool,
+    flips: u32,
+    pub winner: Option<Player>,
+}
+
+impl Race {
+    // a face down copy of the board, in the same order
+    pub fn new(board: &[PlayedCard]) -> Self {
+        Self {
+            board: board
+                .iter()
+                .map(|tile| PlayedCard {
+                    card: tile.card.clone(),
+                    half: tile.half,
+                    displayed: CardState::FaceDown,
+                    matched: false,
+                })
+                .collect(),
+            last: None,
+            needs_reset: false,
+            flips: 0,
+            winner: None,
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.winner.is_some()
+    }
+
+    // a click on a face up tile of the right board turns it all back over
+    pub fn reset(&mut self) {
+        for tile in &mut self.board {
+            tile.displayed = CardState::FaceDown;
+        }
+        self.needs_reset = false;
+        self.last = None;
+    }
+
+    fn swap(&mut self, model: &mut Model) {
+        std::mem::swap(&mut self.board, &mut model.board);
+        std::mem::swap(&mut self.last, &mut model.last);
+        std::mem::swap(&mut self.needs_reset, &mut model.needs_reset);
+        std::mem::swap(&mut self.flips, &mut model.flips);
+    }
+}
+
+// play a click on the right board, and see if that cleared it first
+pub fn guess(model: &mut Model, index: usize) -> game::Outcome {
+    let Some(mut race) = model.race.take() else {
+        return game::Outcome::Ignored;
+    };
+    race.swap(model);
+    let outcome = game::guess(model, index);
+    let cleared = model.all_matched();
+    race.swap(model);
+
+    if cleared && race.winner.is_none() {
+        race.winner = Some(Player::Right);
+    }
+    model.race = Some(race);
+    outcome
+}
+
+// clicks on the right board go to the race instead of the main game
+fn right_click(event: web_sys::Event) -> Option<Msg> {
+    match board_click(event)? {
+        Msg::GuessCard(index) => Some(Msg::RaceGuess(index)),
+        Msg::ResetClick => Some(Msg::RaceReset),
+        _ => None,
+    }
+}
+
+fn side(player: Player, race: &Race, cards: &[Node<Msg>], pairs_found: usize) -> Node<Msg> {
+    let pairs = cards.len() / 2;
+    div![
+        C!["column"],
+        h2![
+            C!["title is-4"],
+            format!("{} · {} of {} pairs", player.name(), pairs_found, pairs),
+            IF!(race.winner == Some(player) => " 🏆"),
+        ],
+        div![
+            board_rows(cards, COLUMNS),
+            match player {
+                Player::Left => ev(Ev::Click, board_click),
+                Player::Right => ev(Ev::Click, right_click),
+            },
+        ],
+    ]
+}
+
+// both boards next to each other. `left` is the main board's tiles, already drawn
+pub fn view(
+    race: &Race,
+    left: &[Node<Msg>],
+    left_found: usize,
+    card_back: &str,
+    loaded_photos: &BTreeSet<Ulid>,
+) -> Node<Msg> {
+    let right: Vec<Node<Msg>> = race
+        .board
+        .iter()
+        .enumerate()
+        .map(|(index, tile)| {
+            print_card(
+                tile,
+                index,
+                card_back,
+                None,
+                loaded_photos.contains(&tile.card.id),
+                false,
+            )
+        })
+        .collect();
+    let right_found = race.board.iter().filter(|tile| tile.matched).count() / 2;
+
+    div![
+        race.winner.map(|winner| {
+            p![
+                C!["title is-2"],
+                style![St::TextAlign => "center"],
+                format!("🏁 {} wins!", winner.name())
+            ]
+        }),
+        div![
+            C!["columns"],
+            side(Player::Left, race, left, left_found),
+            side(Player::Right, race, &right, right_found),
+        ],
+    ]
+}
@@ -0,0 +1,121 @@
+// a look back over every pair after a game, with how often each one was missed, so the game
+// ends by going over the words once more
+use crate::{card_front, speedrun, Msg, PlayedCard};
+use seed::{prelude::*, *};
+use std::collections::{BTreeMap, BTreeSet};
+use ulid::Ulid;
+
+pub fn view(
+    board: &[PlayedCard],
+    misses: &BTreeMap<Ulid, u32>,
+    tile_flips: &[u32],
+    loaded_photos: &BTreeSet<Ulid>,
+    speedrun: Option<&speedrun::Speedrun>,
+    columns: usize,
+) -> Node<Msg> {
+    // one row per card, in the order the tiles were dealt
+    let mut seen = BTreeSet::new();
+    let pairs: Vec<Node<Msg>> = board
+        .iter()
+        .filter(|tile| seen.insert(tile.card.id))
+        .map(|tile| {
+            let id = tile.card.id;
+            let loaded = loaded_photos.contains(&id);
+            let halves = board.iter().filter(|other| other.card.id == id);
+            let missed = misses.get(&id).copied().unwrap_or(0);
+            div![
+                C!["columns is-vcentered"],
+                halves.map(|half| {
+                    div![
+                        C!["column is-3"],
+                        div![C!["card"], card_front(&half.card, half.half, loaded)]
+                    ]
+                }),
+                div![
+                    C!["column"],
+                    tile.card.sound.clone().map(|sound| {
+                        button![
+                            "🔊 Listen",
+                            C!["button"],
+                            ev(Ev::Click, move |_| Msg::Say(sound)),
+                        ]
+                    }),
+                    p![
+                        C!["title is-5"],
+                        match missed {
+                            0 => "✅ no misses".to_string(),
+                            1 => "missed once".to_string(),
+                            missed => format!("missed {} times", missed),
+                        }
+                    ],
+                ],
+            ]
+        })
+        .collect();
+
+    div![
+        C!["box"],
+        h1![C!["title is-4"], "Let's go over the pairs"],
+        pairs,
+        heatmap(board, tile_flips, columns),
+        speedrun.map(|speedrun| speedrun::splits(speedrun, board)),
+        div![
+            C!["buttons"],
+            button![
+                "Play again!",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::StartGame),
+            ],
+            button![
+                "Back to the Board",
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::ToggleReview),
+            ],
+            button![
+                "Create New",
+                C!["button is-large is-warning"],
+                ev(Ev::Click, |_| Msg::ExitGame),
+            ],
+        ],
+    ]
+}
+
+// the board laid out like it was played, each spot shaded by how many times it got turned
+// over, as many across as the board had. the darkest ones are where the memory kept slipping
+fn heatmap(board: &[PlayedCard], tile_flips: &[u32], columns: usize) -> Node<Msg> {
+    let flips = |index: usize| tile_flips.get(index).copied().unwrap_or(0);
+    let most = (0..board.len()).map(flips).max().unwrap_or(0).max(1);
+
+    div![
+        C!["block"],
+        h2![C!["title is-5"], "Where the clicks went"],
+        div![
+            style![
+                St::Display => "grid",
+                St::GridTemplateColumns => format!("repeat({}, 1fr)", columns),
+                St::Gap => px(4),
+                St::MaxWidth => rem(36),
+            ],
+            board.iter().enumerate().map(|(index, tile)| {
+                let flips = flips(index);
+                let heat = f64::from(flips) / f64::from(most);
+                div![
+                    style![
+                        St::Background => format!("rgba(255, 56, 96, {:.2})", 0.1 + 0.9 * heat),
+                        St::Color => if heat > 0.5 { "white" } else { "black" },
+                        St::Padding => px(6),
+                        St::BorderRadius => px(4),
+                        St::TextAlign => "center",
+                        St::Overflow => "hidden",
+                        St::WhiteSpace => "nowrap",
+                        St::TextOverflow => "ellipsis",
+                    ],
+                    attrs! {At::Title => format!("turned over {} times", flips)},
+                    strong![flips.to_string()],
+                    br![],
+                    small![tile.card.text.as_deref().unwrap_or("")],
+                ]
+            }),
+        ],
+    ]
+}
@@ -0,0 +1,143 @@
+// campaign style play: round 1 deals a few pairs, every round after adds more from the deck
+// until it's all on the board, with the score carried from round to round
+use crate::{game::Outcome, Msg};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use seed::{prelude::*, *};
+use std::collections::BTreeSet;
+use ulid::Ulid;
+
+const FIRST_ROUND_PAIRS: usize = 4;
+const PAIRS_ADDED_EACH_ROUND: usize = 2;
+const POINTS_PER_MATCH: u32 = 10;
+const POINTS_PER_MISS: u32 = 2;
+
+pub struct Campaign {
+    // starts at 1
+    round: usize,
+    // the deck shuffled once, each round deals from the front of it
+    order: Vec<Ulid>,
+    matches: u32,
+    misses: u32,
+    round_won: bool,
+}
+
+impl Campaign {
+    pub fn new(mut cards: Vec<Ulid>) -> Self {
+        cards.shuffle(&mut thread_rng());
+        Self {
+            round: 1,
+            order: cards,
+            matches: 0,
+            misses: 0,
+            round_won: false,
+        }
+    }
+
+    fn pairs_this_round(&self) -> usize {
+        (FIRST_ROUND_PAIRS + PAIRS_ADDED_EACH_ROUND * (self.round - 1)).min(self.order.len())
+    }
+
+    // the cards dealt this round
+    pub fn cards(&self) -> BTreeSet<Ulid> {
+        self.order[..self.pairs_this_round()]
+            .iter()
+            .copied()
+            .collect()
+    }
+
+    pub fn record(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Matched => self.matches += 1,
+            Outcome::Missed => self.misses += 1,
+            _ => {}
+        }
+    }
+
+    pub fn won_round(&mut self) {
+        self.round_won = true;
+    }
+
+    // on to a bigger board
+    pub fn next_round(&mut self) {
+        self.round += 1;
+        self.round_won = false;
+    }
+
+    pub fn round_won(&self) -> bool {
+        self.round_won
+    }
+
+    // the last round had the whole deck, and it's been won
+    pub fn is_finished(&self) -> bool {
+        self.round_won && self.pairs_this_round() == self.order.len()
+    }
+
+    fn score(&self) -> u32 {
+        (self.matches * POINTS_PER_MATCH).saturating_sub(self.misses * POINTS_PER_MISS)
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+// round and score, above the board
+pub fn hud(campaign: &Campaign) -> Node<Msg> {
+    p![
+        C!["title is-4"],
+        format!(
+            "Round {} · {} pairs · Score {}",
+            campaign.round,
+            campaign.pairs_this_round(),
+            campaign.score()
+        ),
+    ]
+}
+
+// between rounds, or the summary at the end
+pub fn round_over(campaign: &Campaign) -> Node<Msg> {
+    let (title, button) = if campaign.is_finished() {
+        (
+            format!("🏆 All {} rounds done!", campaign.round),
+            button![
+                "Play Again",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::NewCampaign),
+            ],
+        )
+    } else {
+        (
+            format!("Round {} done!", campaign.round),
+            button![
+                "Next Round",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::NextRound),
+            ],
+        )
+    };
+
+    div![
+        C!["box"],
+        style![St::TextAlign => "center"],
+        h1![C!["title is-4"], title],
+        p![
+            C!["title is-5"],
+            format!(
+                "Score {} · {} pairs found · {} misses",
+                campaign.score(),
+                campaign.matches,
+                campaign.misses
+            ),
+        ],
+        div![
+            C!["buttons"],
+            style![St::JustifyContent => "center"],
+            button,
+            button![
+                "Back to Editor",
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::EditDeck),
+            ],
+        ],
+    ]
+}
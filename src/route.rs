@@ -0,0 +1,58 @@
+// the page in the address bar, so the back button works and a refresh during a game picks it
+// back up instead of starting over. they're hash routes (#/play), github pages can't serve
+// made up paths
+use crate::Model;
+use seed::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum Page {
+    #[default]
+    Edit,
+    Play,
+    Settings,
+}
+
+impl Page {
+    pub fn from_url(url: &Url) -> Option<Self> {
+        match url.hash()?.trim_start_matches('/') {
+            "edit" => Some(Self::Edit),
+            "play" => Some(Self::Play),
+            "settings" => Some(Self::Settings),
+            _ => None,
+        }
+    }
+
+    // the page the app is showing right now
+    pub fn of(model: &Model) -> Self {
+        if model.game_started {
+            Self::Play
+        } else if model.settings_open {
+            Self::Settings
+        } else {
+            Self::Edit
+        }
+    }
+
+    const fn hash(self) -> &'static str {
+        match self {
+            Self::Edit => "#/edit",
+            Self::Play => "#/play",
+            Self::Settings => "#/settings",
+        }
+    }
+
+    // a new history entry, just the hash so the rest of the address (like an exported game's
+    // file name) stays as it is
+    pub fn push(self) {
+        if let Ok(history) = seed::window().history() {
+            let _garbage = history.push_state_with_url(&JsValue::NULL, "", Some(self.hash()));
+        }
+    }
+
+    // the same, without a history entry
+    pub fn replace(self) {
+        if let Ok(history) = seed::window().history() {
+            let _garbage = history.replace_state_with_url(&JsValue::NULL, "", Some(self.hash()));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// the game in progress, saved after every move so closing the tab doesn't lose it
+use crate::{storage, Card, CardState, Model, PairHalf, PlayedCard};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use ulid::Ulid;
+
+#[derive(Serialize, Deserialize)]
+struct SavedTile {
+    id: Ulid,
+    half: PairHalf,
+    face_up: bool,
+    matched: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SavedGame {
+    // every card once, the board only points at them
+    cards: Vec<Card>,
+    board: Vec<SavedTile>,
+    last: Option<Ulid>,
+    needs_reset: bool,
+}
+
+impl SavedGame {
+    pub fn from_model(model: &Model) -> Self {
+        Self {
+            cards: model.cards().cloned().collect(),
+            board: model
+                .board
+                .iter()
+                .map(|tile| SavedTile {
+                    id: tile.card.id,
+                    half: tile.half,
+                    face_up: tile.displayed == CardState::FaceUp,
+                    matched: tile.matched,
+                })
+                .collect(),
+            last: model.last,
+            needs_reset: model.needs_reset,
+        }
+    }
+
+    pub fn pairs(&self) -> usize {
+        self.board.len() / 2
+    }
+
+    pub fn pairs_found(&self) -> usize {
+        self.board.iter().filter(|tile| tile.matched).count() / 2
+    }
+
+    // put the saved game back into the model, exactly like it was. its cards are played in place
+    // of the editor's until it ends, they might not be the same ones anymore
+    pub fn restore(self, model: &mut Model) {
+        let order = self.cards.iter().map(|card| card.id).collect();
+        let cards: BTreeMap<Ulid, Card> =
+            self.cards.into_iter().map(|card| (card.id, card)).collect();
+
+        model.board = self
+            .board
+            .into_iter()
+            .filter_map(|tile| {
+                Some(PlayedCard {
+                    card: cards.get(&tile.id)?.clone(),
+                    half: tile.half,
+                    displayed: if tile.face_up {
+                        CardState::FaceUp
+                    } else {
+                        CardState::FaceDown
+                    },
+                    matched: tile.matched,
+                })
+            })
+            .collect();
+        model.play_other_cards(cards, order);
+        model.last = self.last;
+        model.needs_reset = self.needs_reset;
+        model.game_started = true;
+    }
+}
+
+pub fn load() -> Option<SavedGame> {
+    storage::load::<SavedGame>(storage::SAVED_GAME).filter(|game| !game.board.is_empty())
+}
+
+// save the current game, or forget it once there's nothing left to resume
+pub fn save(model: &Model) {
+    let finished = model.board.iter().all(|tile| tile.matched);
+    if !model.game_started || finished {
+        clear();
+    } else {
+        storage::save(storage::SAVED_GAME, &SavedGame::from_model(model));
+    }
+}
+
+pub fn clear() {
+    storage::remove(storage::SAVED_GAME);
+}
@@ -0,0 +1,108 @@
+// an overall time limit for playing, for station rotations where each group gets a fixed few
+// minutes. the clock starts with the first game and keeps going through "play again"
+use crate::{Msg, PlayedCard};
+use seed::{prelude::*, *};
+
+pub struct Session {
+    // js time (ms) the session is over at
+    ends_at: f64,
+    now: f64,
+    // pairs found in earlier games of this session
+    pairs_before: usize,
+    games_before: usize,
+}
+
+impl Session {
+    pub fn start(minutes: u32) -> Self {
+        let now = js_sys::Date::now();
+        Self {
+            ends_at: now + f64::from(minutes) * 60_000.0,
+            now,
+            pairs_before: 0,
+            games_before: 0,
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.now = js_sys::Date::now();
+    }
+
+    // time spent away from the game doesn't count
+    pub fn pause_for(&mut self, ms: f64) {
+        self.ends_at += ms;
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.now >= self.ends_at
+    }
+
+    // a new game in the same session, keep what the last board got to
+    pub fn next_game(&mut self, board: &[PlayedCard]) {
+        if !board.is_empty() {
+            self.pairs_before += pairs_found(board);
+            self.games_before += 1;
+        }
+    }
+
+    fn seconds_left(&self) -> u64 {
+        // the clock runs in whole seconds, so this is never fractional in practice
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let seconds = ((self.ends_at - self.now) / 1000.0).ceil().max(0.0) as u64;
+        seconds
+    }
+}
+
+fn pairs_found(board: &[PlayedCard]) -> usize {
+    board.iter().filter(|tile| tile.matched).count() / 2
+}
+
+// parse the minutes setting, empty or 0 means no limit
+pub fn minutes(setting: &str) -> Option<u32> {
+    setting.trim().parse().ok().filter(|minutes| *minutes > 0)
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn countdown(session: &Session) -> Node<Msg> {
+    let seconds = session.seconds_left();
+    p![
+        C!["title is-4", IF!(seconds <= 60 => "has-text-danger")],
+        format!("⏱ {}:{:02} left", seconds / 60, seconds % 60),
+    ]
+}
+
+// shown instead of the board once time is up
+pub fn wrap_up(session: &Session, board: &[PlayedCard]) -> Node<Msg> {
+    let pairs = session.pairs_before + pairs_found(board);
+    let games = session.games_before + 1;
+    div![
+        C!["box"],
+        style![St::TextAlign => "center"],
+        h1![C!["title is-4"], "⏰ Time's up!"],
+        p![
+            C!["title is-5"],
+            format!(
+                "{} {} found in {} {}",
+                pairs,
+                if pairs == 1 { "pair" } else { "pairs" },
+                games,
+                if games == 1 { "game" } else { "games" },
+            )
+        ],
+        div![
+            C!["buttons"],
+            style![St::JustifyContent => "center"],
+            button![
+                "Next Group",
+                C!["button is-large is-success"],
+                ev(Ev::Click, |_| Msg::NewSession),
+            ],
+            button![
+                "Back to Editor",
+                C!["button is-large"],
+                ev(Ev::Click, |_| Msg::EditDeck),
+            ],
+        ],
+    ]
+}
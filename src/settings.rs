@@ -0,0 +1,44 @@
+// the settings that should outlast a reload, kept together under one key: the board's look, and
+// the outside services a teacher has set up (their addresses and keys)
+use crate::{
+    global_leaderboard::GlobalLeaderboardSettings, image_gen::ImageGenSettings,
+    lookup::LookupSettings, skins::Skin, stock_photos::StockPhotoSettings, storage,
+    webhook::WebhookSettings, Model,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Serialize, Deserialize)]
+// (settings added later are missing from older saves)
+#[serde(default)]
+struct Settings {
+    skin: Skin,
+    stock_photos: StockPhotoSettings,
+    image_gen: ImageGenSettings,
+    lookup: LookupSettings,
+    webhook: WebhookSettings,
+    global_leaderboard: GlobalLeaderboardSettings,
+}
+
+// put the saved settings into a fresh model
+pub fn load(model: &mut Model) {
+    if let Some(settings) = storage::load::<Settings>(storage::SETTINGS) {
+        model.skin = settings.skin;
+        model.stock_photos = settings.stock_photos;
+        model.image_gen = settings.image_gen;
+        model.lookup = settings.lookup;
+        model.webhook = settings.webhook;
+        model.global_leaderboard = settings.global_leaderboard;
+    }
+}
+
+pub fn save(model: &Model) {
+    let settings = Settings {
+        skin: model.skin,
+        stock_photos: model.stock_photos.clone(),
+        image_gen: model.image_gen.clone(),
+        lookup: model.lookup.clone(),
+        webhook: model.webhook.clone(),
+        global_leaderboard: model.global_leaderboard.clone(),
+    };
+    storage::save(storage::SETTINGS, &settings);
+}
@@ -0,0 +1,64 @@
+// deck links: the deck's words, deflated and packed into the link's #fragment, so a colleague
+// who opens it gets the same cards in their editor. pictures and sounds stay behind, they'd make
+// a link too long to paste anywhere
+use crate::deck::Deck;
+use seed::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const HASH_PREFIX: &str = "deck=";
+
+#[derive(Serialize, Deserialize)]
+pub struct Shared {
+    // the deck's saved name, so saving it on the other end keeps it
+    pub name: String,
+    pub deck: Deck,
+}
+
+impl Shared {
+    // the deck without its pictures and sounds, leaving out cards that were only a picture
+    pub fn new(name: &str, mut deck: Deck) -> Self {
+        for card in &mut deck.cards {
+            card.photo = None;
+            card.preview = None;
+            card.audio = None;
+        }
+        deck.cards.retain(|card| card.skip_reason().is_none());
+        Self {
+            name: name.to_string(),
+            deck,
+        }
+    }
+
+    pub fn link(&self) -> String {
+        let json = serde_json::to_vec(self).expect("serialize shared deck");
+        let packed = miniz_oxide::deflate::compress_to_vec(&json, 9);
+        format!(
+            "{}#{}{}",
+            page_address(),
+            HASH_PREFIX,
+            base64::encode_config(packed, base64::URL_SAFE_NO_PAD)
+        )
+    }
+
+    // the deck in the url the app was opened with, if there is one
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let encoded = url.hash()?.strip_prefix(HASH_PREFIX)?;
+        let packed = base64::decode_config(encoded, base64::URL_SAFE_NO_PAD).ok()?;
+        let json = miniz_oxide::inflate::decompress_to_vec(&packed).ok()?;
+        let shared: Self = serde_json::from_slice(&json)
+            .map_err(|error| seed::log!("couldn't read the shared deck:", error))
+            .ok()?;
+        shared.deck.validate().ok()?;
+        Some(shared)
+    }
+}
+
+// this page's address without any #fragment, for links back to it
+pub fn page_address() -> String {
+    let location = seed::window().location();
+    format!(
+        "{}{}",
+        location.origin().unwrap_or_default(),
+        location.pathname().unwrap_or_default()
+    )
+}
@@ -0,0 +1,115 @@
+// seasonal looks for the game board: card backs, background and the win celebration
+use seed::{prelude::*, *};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Skin {
+    #[default]
+    Classic,
+    Winter,
+    Space,
+    Jungle,
+}
+
+impl Skin {
+    pub const ALL: [Self; 4] = [Self::Classic, Self::Winter, Self::Space, Self::Jungle];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::Winter => "Winter",
+            Self::Space => "Space",
+            Self::Jungle => "Jungle",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|skin| skin.name() == name)
+    }
+
+    // the picture on the back of every face down card
+    pub fn card_back(self) -> String {
+        let (color, emoji) = match self {
+            Self::Classic => return crate::QUESTION_IMG.to_string(),
+            Self::Winter => ("#d6ecfa", "❄️"),
+            Self::Space => ("#1a1446", "🪐"),
+            Self::Jungle => ("#2e7d32", "🌴"),
+        };
+        let svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100"><rect width="100" height="100" fill="{}"/><text x="50" y="54" font-size="56" text-anchor="middle" dominant-baseline="central">{}</text></svg>"#,
+            color, emoji
+        );
+        format!("data:image/svg+xml;base64,{}", base64::encode(svg))
+    }
+
+    const fn background(self) -> &'static str {
+        match self {
+            Self::Classic => "none",
+            Self::Winter => "linear-gradient(#e3f2fd, #ffffff)",
+            Self::Space => "radial-gradient(circle at top, #3a2f7a, #0b0824)",
+            Self::Jungle => "linear-gradient(#a5d6a7, #1b5e20)",
+        }
+    }
+
+    // what rains down when the board is cleared
+    const fn celebration(self) -> [&'static str; 3] {
+        match self {
+            Self::Classic => ["🎉", "⭐", "🎈"],
+            Self::Winter => ["❄️", "⛄", "🎿"],
+            Self::Space => ["🚀", "⭐", "🌙"],
+            Self::Jungle => ["🐒", "🦜", "🍌"],
+        }
+    }
+}
+
+// wrap the game page in the skin's background
+pub fn board<Ms>(skin: Skin, content: Vec<Node<Ms>>) -> Node<Ms> {
+    div![
+        style![
+            St::Background => skin.background(),
+            St::MinHeight => vh(100),
+            St::Padding => px(12),
+        ],
+        content,
+    ]
+}
+
+// emoji falling down the screen, shown once every pair is found. `fade_in` goes from 0 to 1 as
+// it appears
+pub fn celebration<Ms>(skin: Skin, fade_in: f64) -> Node<Ms> {
+    let emoji = skin.celebration();
+    div![
+        style![
+            St::Position => "fixed",
+            St::Top => 0,
+            St::Left => 0,
+            St::Width => vw(100),
+            St::Height => vh(100),
+            St::PointerEvents => "none",
+            St::Overflow => "hidden",
+            St::ZIndex => 10,
+            St::Opacity => fade_in,
+        ],
+        custom![
+            Tag::Style,
+            "@keyframes skin-fall { from { transform: translateY(-10vh) rotate(0deg); } to { transform: translateY(110vh) rotate(360deg); } }"
+        ],
+        (0..24_u32).map(|index| {
+            span![
+                emoji[index as usize % emoji.len()],
+                style![
+                    St::Position => "absolute",
+                    St::Top => 0,
+                    St::Left => percent(f64::from(index * 37 % 100)),
+                    St::FontSize => rem(2 + index % 3),
+                    St::Animation => format!(
+                        "skin-fall {}s linear {}s infinite",
+                        3 + index % 4,
+                        f64::from(index % 8) * 0.4
+                    ),
+                ],
+            ]
+        }),
+    ]
+}
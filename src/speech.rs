@@ -0,0 +1,110 @@
+// text to speech helpers using the browser's SpeechSynthesis api
+use seed::prelude::*;
+use web_sys::{SpeechSynthesis, SpeechSynthesisUtterance, SpeechSynthesisVoice};
+
+const DEFAULT_LANG: &str = "en-US";
+
+// the language and voice a deck should be read with
+#[derive(Clone)]
+pub struct SpeechSettings {
+    // BCP-47 language tag, like "en-US" or "es-ES"
+    pub lang: String,
+    // name of the preferred voice, None lets the browser pick one for the language
+    pub voice: Option<String>,
+    // say each word as its card turns over, for practicing the language
+    pub read_words: bool,
+}
+
+impl Default for SpeechSettings {
+    fn default() -> Self {
+        Self {
+            lang: DEFAULT_LANG.to_string(),
+            voice: None,
+            read_words: false,
+        }
+    }
+}
+
+// a voice the browser says it has
+#[derive(Clone)]
+pub struct Voice {
+    pub name: String,
+    pub lang: String,
+}
+
+impl Voice {
+    // true if this voice can read the given language ("es" matches "es-MX" and the other way around)
+    pub fn speaks(&self, lang: &str) -> bool {
+        primary_subtag(&self.lang).eq_ignore_ascii_case(primary_subtag(lang))
+    }
+}
+
+fn primary_subtag(lang: &str) -> &str {
+    lang.split(['-', '_']).next().unwrap_or("")
+}
+
+fn synth() -> Option<SpeechSynthesis> {
+    web_sys::window()?.speech_synthesis().ok()
+}
+
+// speech synthesis isn't in every browser (or webview), so check before showing options
+pub fn is_supported() -> bool {
+    synth().is_some()
+}
+
+fn browser_voices() -> Vec<SpeechSynthesisVoice> {
+    synth().map_or_else(Vec::new, |synth| {
+        synth
+            .get_voices()
+            .iter()
+            .filter_map(|voice| voice.dyn_into::<SpeechSynthesisVoice>().ok())
+            .collect()
+    })
+}
+
+// list the voices the browser has right now
+// (chrome loads these async, so listen with on_voices_changed too)
+pub fn available_voices() -> Vec<Voice> {
+    browser_voices()
+        .iter()
+        .map(|voice| Voice {
+            name: voice.name(),
+            lang: voice.lang(),
+        })
+        .collect()
+}
+
+// call `handler` whenever the browser finishes loading (or changes) its voice list
+pub fn on_voices_changed(handler: impl Fn() + 'static) {
+    if let Some(synth) = synth() {
+        let closure = Closure::wrap(Box::new(handler) as Box<dyn Fn()>);
+        synth.set_onvoiceschanged(Some(closure.as_ref().unchecked_ref()));
+        // the listener lives as long as the app does
+        closure.forget();
+    }
+}
+
+// read `text` out loud with the deck's language and voice
+pub fn speak(text: &str, settings: &SpeechSettings) {
+    let synth = match synth() {
+        Some(synth) => synth,
+        None => return,
+    };
+    let utterance = match SpeechSynthesisUtterance::new_with_text(text) {
+        Ok(utterance) => utterance,
+        Err(_) => return,
+    };
+    utterance.set_lang(&settings.lang);
+
+    // use the preferred voice if the browser still has it, otherwise the language decides
+    if let Some(name) = &settings.voice {
+        let voices = browser_voices();
+        if let Some(voice) = voices.iter().find(|voice| &voice.name() == name) {
+            utterance.set_voice(Some(voice));
+        }
+    }
+
+    // stop anything still talking so quick flips don't queue up
+    synth.cancel();
+    synth.speak(&utterance);
+}
@@ -0,0 +1,131 @@
+// millisecond timing for competitive play. the clock starts with the first tile turned over,
+// and every pair found gets a split, like a speedrun
+use crate::{Msg, PlayedCard};
+use seed::{prelude::*, *};
+use ulid::Ulid;
+
+// how often the live timer is redrawn
+pub const REFRESH_MS: u32 = 31;
+
+// a high resolution clock, falling back to the date if there's no performance api
+fn now() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map_or_else(js_sys::Date::now, |performance| performance.now())
+}
+
+#[derive(Default)]
+pub struct Speedrun {
+    started_at: Option<f64>,
+    now: f64,
+    // when each pair was found, in ms since the start
+    splits: Vec<(Ulid, f64)>,
+    finished: Option<f64>,
+    // the clock is stopped while a card gets fixed mid game
+    paused_at: Option<f64>,
+}
+
+impl Speedrun {
+    // the first tile was turned over
+    pub fn start(&mut self) {
+        if self.started_at.is_none() {
+            let now = now();
+            self.started_at = Some(now);
+            self.now = now;
+        }
+    }
+
+    pub fn tick(&mut self) {
+        if self.paused_at.is_none() {
+            self.now = now();
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if self.paused_at.is_none() {
+            self.paused_at = Some(now());
+        }
+    }
+
+    // pick up again without counting the time spent away
+    pub fn resume(&mut self) {
+        if let Some(paused_at) = self.paused_at.take() {
+            let now = now();
+            if let Some(started_at) = &mut self.started_at {
+                *started_at += now - paused_at;
+            }
+            self.now = now;
+        }
+    }
+
+    pub fn split(&mut self, id: Ulid) {
+        if let Some(started_at) = self.started_at {
+            self.splits.push((id, now() - started_at));
+        }
+    }
+
+    pub fn finish(&mut self) {
+        self.finished = Some(self.elapsed());
+    }
+
+    pub fn elapsed(&self) -> f64 {
+        self.finished.unwrap_or_else(|| {
+            self.started_at
+                .map_or(0.0, |started_at| (self.now - started_at).max(0.0))
+        })
+    }
+
+    pub fn splits(&self) -> &[(Ulid, f64)] {
+        &self.splits
+    }
+}
+
+// mm:ss.mmm
+pub fn format_ms(ms: f64) -> String {
+    // never negative and far below u64::MAX
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let ms = ms.round().max(0.0) as u64;
+    format!("{:02}:{:02}.{:03}", ms / 60_000, ms / 1000 % 60, ms % 1000)
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn timer(speedrun: &Speedrun) -> Node<Msg> {
+    p![
+        C!["title is-3"],
+        style![St::FontFamily => "monospace"],
+        format!("⏱ {}", format_ms(speedrun.elapsed())),
+    ]
+}
+
+// the time each pair was found at, and how long it took after the one before
+pub fn splits(speedrun: &Speedrun, board: &[PlayedCard]) -> Node<Msg> {
+    if speedrun.splits.is_empty() {
+        return empty![];
+    }
+    let mut previous = 0.0;
+    div![
+        C!["block"],
+        h2![C!["title is-5"], "Splits"],
+        table![
+            C!["table is-narrow"],
+            style![St::FontFamily => "monospace"],
+            speedrun.splits.iter().enumerate().map(|(index, (id, at))| {
+                let text = board
+                    .iter()
+                    .find(|tile| tile.card.id == *id)
+                    .and_then(|tile| tile.card.text.as_deref())
+                    .unwrap_or("");
+                let split = at - previous;
+                previous = *at;
+                tr![
+                    td![index + 1],
+                    td![text],
+                    td![format_ms(*at)],
+                    td![format!("+{}", format_ms(split))],
+                ]
+            }),
+        ],
+    ]
+}
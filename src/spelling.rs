@@ -0,0 +1,77 @@
+// spelling practice: after each pair is found, type the word that was on it. the deck's answer
+// policy decides how close the spelling has to be
+use crate::{game::AnswerPolicy, Msg};
+use seed::{prelude::*, *};
+
+pub struct Spelling {
+    // the word on the pair that was just found
+    word: String,
+    typed: String,
+    // whether it was spelled right, once it's been checked
+    checked: Option<bool>,
+}
+
+impl Spelling {
+    pub fn new(word: String) -> Self {
+        Self {
+            word,
+            typed: String::new(),
+            checked: None,
+        }
+    }
+
+    pub fn type_in(&mut self, typed: String) {
+        self.typed = typed;
+        self.checked = None;
+    }
+
+    pub fn check(&mut self, policy: AnswerPolicy) {
+        self.checked = Some(policy.accepts(&self.typed, &self.word));
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn view(spelling: &Spelling) -> Node<Msg> {
+    div![
+        C!["box"],
+        button![
+            C!["delete is-pulled-right"],
+            attrs! {At::AriaLabel => "skip"},
+            ev(Ev::Click, |_| Msg::SkipSpelling),
+        ],
+        label![C!["label"], "Type the word you just found"],
+        div![
+            C!["field has-addons"],
+            div![
+                C!["control is-expanded"],
+                input![
+                    C!["input"],
+                    attrs! {At::Value => spelling.typed, At::AutoFocus => AtValue::None},
+                    input_ev(Ev::Input, Msg::TypeSpelling),
+                    keyboard_ev(Ev::KeyDown, |event| {
+                        IF!(event.key() == "Enter" => Msg::CheckSpelling)
+                    }),
+                ],
+            ],
+            div![
+                C!["control"],
+                button![
+                    "Check",
+                    C!["button is-info"],
+                    ev(Ev::Click, |_| Msg::CheckSpelling),
+                ],
+            ],
+        ],
+        match spelling.checked {
+            Some(true) => p![C!["has-text-success"], "✓ spelled right!"],
+            Some(false) => p![
+                C!["has-text-danger"],
+                "not quite, it's spelled ",
+                strong![&spelling.word],
+            ],
+            None => empty![],
+        },
+    ]
+}
@@ -0,0 +1,58 @@
+// a single html file with the deck inside it, that opens straight into a game
+//
+// the app itself still comes from wherever it's hosted, so the file stays small
+use crate::deck::Deck;
+
+// the script element the app checks for a deck when it starts
+pub const DECK_ELEMENT_ID: &str = "matching-seed-deck";
+
+// where the app is being served from, like "https://example.github.io/"
+fn site_root() -> String {
+    seed::window()
+        .location()
+        .origin()
+        .map_or_else(|_| "/".to_string(), |origin| format!("{}/", origin))
+}
+
+pub fn html(deck: &Deck) -> String {
+    // "</" can't show up inside a script element, and "<\/" means the same thing in json
+    let deck_json = deck.to_json().replace("</", "<\\/");
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1, shrink-to-fit=no">
+    <title>Matching!</title>
+    <base href="{root}">
+    <link rel="stylesheet" href="https://cdn.jsdelivr.net/npm/bulma@0.9.2/css/bulma.min.css">
+</head>
+<body>
+    <section id="app"></section>
+    <script id="{id}" type="application/json">{deck}</script>
+    <script type="module">
+        import init from '{root}matching-seed/pkg/package.js';
+        init('{root}matching-seed/pkg/package_bg.wasm');
+    </script>
+</body>
+</html>
+"#,
+        root = site_root(),
+        id = DECK_ELEMENT_ID,
+        deck = deck_json,
+    )
+}
+
+// the deck in the page this app was started from, if it's an exported game
+pub fn embedded_deck() -> Option<Deck> {
+    let element = seed::document().get_element_by_id(DECK_ELEMENT_ID)?;
+    let json = element.text_content()?;
+    match Deck::from_json(&json) {
+        Ok(deck) => Some(deck),
+        Err(error) => {
+            seed::log!("couldn't read the embedded deck:", error);
+            None
+        }
+    }
+}
@@ -0,0 +1,56 @@
+// how each card has gone over past games, kept in the browser so picking a few pairs out of a
+// big deck can lean toward the words that keep getting missed
+use crate::storage;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use ulid::Ulid;
+
+// how much more likely a card that's always missed is to be picked than one that never is
+const MISS_WEIGHT: f64 = 4.0;
+
+#[derive(Default, Serialize, Deserialize)]
+struct CardStats {
+    played: u32,
+    misses: u32,
+}
+
+#[derive(Default)]
+pub struct Stats {
+    cards: BTreeMap<Ulid, CardStats>,
+}
+
+impl Stats {
+    pub fn load() -> Self {
+        Self {
+            cards: storage::load(storage::CARD_STATS).unwrap_or_default(),
+        }
+    }
+
+    fn save(&self) {
+        storage::save(storage::CARD_STATS, &self.cards);
+    }
+
+    // the cards were dealt into a new game
+    pub fn played(&mut self, ids: impl IntoIterator<Item = Ulid>) {
+        for id in ids {
+            self.cards.entry(id).or_default().played += 1;
+        }
+        self.save();
+    }
+
+    // a wrong guess, both tiles count as missed
+    pub fn missed(&mut self, ids: impl IntoIterator<Item = Ulid>) {
+        for id in ids {
+            self.cards.entry(id).or_default().misses += 1;
+        }
+        self.save();
+    }
+
+    // how likely the card should be to get picked, 1 for a card that's never missed
+    pub fn weight(&self, id: Ulid) -> f64 {
+        self.cards.get(&id).map_or(1.0, |stats| {
+            let miss_rate = f64::from(stats.misses) / f64::from(stats.played.max(1));
+            1.0 + MISS_WEIGHT * miss_rate.min(1.0)
+        })
+    }
+}
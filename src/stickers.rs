@@ -0,0 +1,142 @@
+// a sticker for every win, collected in a sticker book that's kept in the browser, so little
+// kids have something to play for
+use crate::storage;
+use rand::prelude::*;
+use seed::{prelude::*, *};
+use std::collections::BTreeMap;
+
+const STICKERS: [&str; 24] = [
+    "🦄", "🐶", "🐱", "🐼", "🦊", "🐸", "🐵", "🐧", "🦁", "🐯", "🐨", "🐰", "🦋", "🐢", "🐙", "🦖",
+    "🚀", "🌈", "⭐", "🍦", "🍩", "🎸", "🏆", "🌻",
+];
+
+// ------ ------
+//     Model
+// ------ ------
+#[derive(Default)]
+pub struct Model {
+    open: bool,
+    // how many of each sticker have been won
+    book: BTreeMap<String, u32>,
+    // the one from the last win, shown on the win screen
+    just_won: Option<&'static str>,
+}
+
+impl Model {
+    pub fn load() -> Self {
+        Self {
+            book: storage::load(storage::STICKERS).unwrap_or_default(),
+            ..Self::default()
+        }
+    }
+
+    // pick a random sticker and put it in the book
+    pub fn award(&mut self) {
+        let sticker = *STICKERS.choose(&mut thread_rng()).unwrap_or(&STICKERS[0]);
+        *self.book.entry(sticker.to_string()).or_default() += 1;
+        self.just_won = Some(sticker);
+        storage::save(storage::STICKERS, &self.book);
+    }
+
+    // a new game, so the last sticker is old news
+    pub fn clear_award(&mut self) {
+        self.just_won = None;
+    }
+
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+pub enum Msg {
+    Toggle,
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Toggle => model.open = !model.open,
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+// the sticker that was just won, for the win screen
+pub fn award_view(model: &Model) -> Node<Msg> {
+    match model.just_won {
+        Some(sticker) => div![
+            C!["box"],
+            style![St::TextAlign => "center"],
+            p![C!["title is-5"], "You won a sticker!"],
+            p![style![St::FontSize => rem(5)], sticker],
+            button![
+                "See my sticker book",
+                C!["button is-info"],
+                ev(Ev::Click, |_| Msg::Toggle),
+            ],
+        ],
+        None => empty![],
+    }
+}
+
+// every sticker there is, the ones not won yet as empty spots
+pub fn book_view(model: &Model) -> Node<Msg> {
+    if !model.open {
+        return empty![];
+    }
+
+    let collected = STICKERS
+        .iter()
+        .filter(|sticker| model.book.contains_key(**sticker))
+        .count();
+
+    div![
+        C!["box"],
+        h2![
+            C!["title is-4"],
+            format!("My sticker book ({} of {})", collected, STICKERS.len())
+        ],
+        div![
+            style![
+                St::Display => "grid",
+                St::GridTemplateColumns => "repeat(6, 1fr)",
+                St::Gap => rem(0.5),
+                St::MaxWidth => rem(30),
+            ],
+            STICKERS.iter().map(|sticker| {
+                let count = model.book.get(*sticker).copied().unwrap_or(0);
+                div![
+                    style![
+                        St::FontSize => rem(2.5),
+                        St::TextAlign => "center",
+                        St::Border => "2px dashed #dbdbdb",
+                        St::BorderRadius => px(8),
+                        St::Position => "relative",
+                    ],
+                    if count == 0 {
+                        span![
+                            style![St::Opacity => 0.15, St::Filter => "grayscale(1)"],
+                            "❔"
+                        ]
+                    } else {
+                        span![sticker]
+                    },
+                    IF!(count > 1 => span![
+                        C!["tag is-rounded is-info"],
+                        style![St::Position => "absolute", St::Top => 0, St::Right => 0],
+                        format!("×{}", count)
+                    ]),
+                ]
+            }),
+        ],
+        button![
+            "Close",
+            C!["button"],
+            style![St::MarginTop => rem(1)],
+            ev(Ev::Click, |_| Msg::Toggle),
+        ],
+    ]
+}
@@ -0,0 +1,283 @@
+// search free stock photo sites so pictures can be added without downloading them first
+use crate::{Model, Msg, NewCardType};
+use seed::{prelude::*, *};
+use serde::{Deserialize, Serialize};
+
+const RESULTS_PER_PAGE: u32 = 20;
+
+#[derive(Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Provider {
+    #[default]
+    Openverse,
+    Unsplash,
+    Pixabay,
+}
+
+impl Provider {
+    pub const ALL: [Self; 3] = [Self::Openverse, Self::Unsplash, Self::Pixabay];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Openverse => "Openverse",
+            Self::Unsplash => "Unsplash",
+            Self::Pixabay => "Pixabay",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|provider| provider.name() == name)
+    }
+
+    // openverse works without a key, the other two need one
+    pub const fn needs_key(self) -> bool {
+        !matches!(self, Self::Openverse)
+    }
+}
+
+// which site to search, and the teacher's own api key for it
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StockPhotoSettings {
+    pub provider: Provider,
+    pub api_key: String,
+}
+
+// one search result
+#[derive(Clone)]
+pub struct Photo {
+    // small picture to show in the results
+    pub preview: String,
+    // bigger picture that gets downloaded and turned into a card
+    pub full: String,
+    pub credit: String,
+}
+
+#[derive(Default)]
+pub struct Search {
+    pub query: String,
+    pub results: Vec<Photo>,
+    pub searching: bool,
+    pub error: Option<String>,
+}
+
+// ------ ------
+//  Api responses
+// ------ ------
+#[derive(Deserialize)]
+struct OpenverseResponse {
+    results: Vec<OpenverseImage>,
+}
+
+#[derive(Deserialize)]
+struct OpenverseImage {
+    thumbnail: String,
+    creator: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct UnsplashResponse {
+    results: Vec<UnsplashImage>,
+}
+
+#[derive(Deserialize)]
+struct UnsplashImage {
+    urls: UnsplashUrls,
+    user: UnsplashUser,
+}
+
+#[derive(Deserialize)]
+struct UnsplashUrls {
+    thumb: String,
+    small: String,
+}
+
+#[derive(Deserialize)]
+struct UnsplashUser {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct PixabayResponse {
+    hits: Vec<PixabayImage>,
+}
+
+#[derive(Deserialize)]
+struct PixabayImage {
+    #[serde(rename = "previewURL")]
+    preview_url: String,
+    #[serde(rename = "webformatURL")]
+    webformat_url: String,
+    user: String,
+}
+
+fn encode(text: &str) -> String {
+    String::from(js_sys::encode_uri_component(text))
+}
+
+fn fetch_error(error: &FetchError) -> String {
+    format!("search failed: {:?}", error)
+}
+
+// ask the chosen site for pictures matching `query`
+pub async fn search(settings: StockPhotoSettings, query: String) -> Result<Vec<Photo>, String> {
+    if settings.provider.needs_key() && settings.api_key.trim().is_empty() {
+        return Err(format!(
+            "{} needs an api key, add one in settings",
+            settings.provider.name()
+        ));
+    }
+    let key = settings.api_key.trim();
+    let query = encode(query.trim());
+
+    match settings.provider {
+        Provider::Openverse => {
+            let url = format!(
+                "https://api.openverse.org/v1/images/?q={}&page_size={}",
+                query, RESULTS_PER_PAGE
+            );
+            let mut request = Request::new(url);
+            if !key.is_empty() {
+                request = request.header(Header::bearer(key.to_string()));
+            }
+            let response: OpenverseResponse = fetch_json(request).await?;
+            Ok(response
+                .results
+                .into_iter()
+                .map(|image| Photo {
+                    preview: image.thumbnail.clone(),
+                    full: image.thumbnail,
+                    credit: image.creator.unwrap_or_default(),
+                })
+                .collect())
+        }
+        Provider::Unsplash => {
+            let url = format!(
+                "https://api.unsplash.com/search/photos?query={}&per_page={}",
+                query, RESULTS_PER_PAGE
+            );
+            let request =
+                Request::new(url).header(Header::authorization(format!("Client-ID {}", key)));
+            let response: UnsplashResponse = fetch_json(request).await?;
+            Ok(response
+                .results
+                .into_iter()
+                .map(|image| Photo {
+                    preview: image.urls.thumb,
+                    full: image.urls.small,
+                    credit: image.user.name,
+                })
+                .collect())
+        }
+        Provider::Pixabay => {
+            let url = format!(
+                "https://pixabay.com/api/?key={}&q={}&image_type=photo&safesearch=true&per_page={}",
+                encode(key),
+                query,
+                RESULTS_PER_PAGE
+            );
+            let response: PixabayResponse = fetch_json(Request::new(url)).await?;
+            Ok(response
+                .hits
+                .into_iter()
+                .map(|image| Photo {
+                    preview: image.preview_url,
+                    full: image.webformat_url,
+                    credit: image.user,
+                })
+                .collect())
+        }
+    }
+}
+
+async fn fetch_json<T: serde::de::DeserializeOwned + 'static>(
+    request: Request<'_>,
+) -> Result<T, String> {
+    request
+        .fetch()
+        .await
+        .and_then(Response::check_status)
+        .map_err(|error| fetch_error(&error))?
+        .json()
+        .await
+        .map_err(|error| fetch_error(&error))
+}
+
+// download a result and run it through the same thumbnail code as dropped files
+pub async fn download(photo: Photo) -> Msg {
+    let bytes = match fetch(photo.full.as_str())
+        .await
+        .and_then(Response::check_status)
+    {
+        Ok(response) => response.bytes().await,
+        Err(error) => Err(error),
+    };
+
+    match bytes {
+        Ok(bytes) => match crate::thumbnail::data_url(&bytes) {
+            Ok(url) => Msg::NewCard(NewCardType::OnePhoto(url)),
+            Err(error) => Msg::PhotoSearchFailed(format!("couldn't read that picture: {}", error)),
+        },
+        Err(error) => Msg::PhotoSearchFailed(format!("download failed: {:?}", error)),
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn view(model: &Model) -> Node<Msg> {
+    let search = &model.photo_search;
+
+    let results = search.results.iter().enumerate().map(|(index, photo)| {
+        div![
+            C!["column is-2"],
+            figure![
+                C!["image is-square"],
+                img![attrs! {
+                    At::Src => photo.preview,
+                    At::Alt => photo.credit,
+                    At::Title => format!("add this picture (by {})", photo.credit),
+                }],
+                style![St::Cursor => "pointer"],
+                ev(Ev::Click, move |_| Msg::AddSearchedPhoto(index)),
+            ]
+        ]
+    });
+
+    div![
+        C!["box"],
+        div![
+            C!["field has-addons"],
+            div![
+                C!["control is-expanded"],
+                input![
+                    C!["input"],
+                    attrs! {
+                        At::Value => search.query,
+                        At::Placeholder => format!("search {} for pictures", model.stock_photos.provider.name()),
+                    },
+                    input_ev(Ev::Input, Msg::SetPhotoQuery),
+                    keyboard_ev(Ev::KeyDown, |event| {
+                        IF!(event.key() == "Enter" => Msg::SearchPhotos)
+                    }),
+                ],
+            ],
+            div![
+                C!["control"],
+                button![
+                    "Search",
+                    C!["button is-info", IF!(search.searching => "is-loading")],
+                    ev(Ev::Click, |_| Msg::SearchPhotos),
+                ],
+            ],
+        ],
+        search
+            .error
+            .as_ref()
+            .map(|error| p![C!["help is-danger"], error]),
+        div![C!["columns is-multiline is-mobile"], results],
+    ]
+}
@@ -0,0 +1,126 @@
+// small wrapper around localStorage so a full or blocked storage never takes the app down. the
+// big things (decks and word lists with photos) go to IndexedDB instead, see the `_large`
+// functions
+use crate::idb;
+use seed::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+// everything the app stores starts with this, to stay out of the way of other apps on the site
+const PREFIX: &str = "matching-seed:";
+
+pub const SAVED_GAME: &str = "saved-game";
+pub const DRAFT: &str = "draft";
+pub const UNSAVED_DRAFT: &str = "unsaved-draft";
+pub const DECKS: &str = "decks";
+pub const CARD_STATS: &str = "card-stats";
+pub const STICKERS: &str = "stickers";
+pub const LEADERBOARDS: &str = "leaderboards";
+pub const BEST_TIMES: &str = "best-times";
+pub const SETTINGS: &str = "settings";
+
+// IndexedDB writes waiting their turn, by name: the json to put, or None to delete. only the
+// latest one for each name is kept, and one writer goes through them so they land in order
+#[derive(Default)]
+struct Writes {
+    waiting: BTreeMap<String, Option<String>>,
+    writing: bool,
+}
+
+thread_local! {
+    static WRITES: RefCell<Writes> = RefCell::default();
+}
+
+fn key(name: &str) -> String {
+    format!("{}{}", PREFIX, name)
+}
+
+pub fn load<T: DeserializeOwned>(name: &str) -> Option<T> {
+    LocalStorage::get(key(name)).ok()
+}
+
+pub fn save<T: Serialize + ?Sized>(name: &str, value: &T) {
+    // photos can fill storage up, losing a save is better than crashing the game
+    if let Err(error) = LocalStorage::insert(key(name), value) {
+        seed::log!("couldn't save", name, error);
+    }
+}
+
+pub fn remove(name: &str) {
+    let _garbage = LocalStorage::remove(key(name));
+}
+
+// from IndexedDB, or from localStorage when it was saved before it moved (or the browser has no
+// IndexedDB, like some private windows)
+pub async fn load_large<T: DeserializeOwned>(name: &str) -> Option<T> {
+    match idb::get(&key(name)).await {
+        Ok(Some(json)) => serde_json::from_str(&json)
+            .map_err(|error| seed::log!("couldn't read", name, error))
+            .ok(),
+        _ => load(name),
+    }
+}
+
+// saves in the background. the old localStorage copy goes once it's safe in IndexedDB, freeing
+// its space for everything else
+pub fn save_large<T: Serialize + ?Sized>(name: &str, value: &T) {
+    match serde_json::to_string(value) {
+        Ok(json) => write_later(name, Some(json)),
+        Err(error) => seed::log!("couldn't save", name, error),
+    }
+}
+
+pub fn remove_large(name: &str) {
+    remove(name);
+    write_later(name, None);
+}
+
+fn write_later(name: &str, json: Option<String>) {
+    let start = WRITES.with(|writes| {
+        let mut writes = writes.borrow_mut();
+        writes.waiting.insert(name.to_string(), json);
+        !std::mem::replace(&mut writes.writing, true)
+    });
+    if start {
+        seed::wasm_bindgen_futures::spawn_local(write_all());
+    }
+}
+
+// until nothing's waiting, including anything saved while it was writing
+async fn write_all() {
+    loop {
+        let next = WRITES.with(|writes| {
+            let mut writes = writes.borrow_mut();
+            let next = writes.waiting.pop_first();
+            writes.writing = next.is_some();
+            next
+        });
+        match next {
+            Some((name, Some(json))) => write(&name, &json).await,
+            Some((name, None)) => {
+                let _garbage = idb::delete(&key(&name)).await;
+                // (in case a put before it couldn't get into IndexedDB either)
+                remove(&name);
+            }
+            None => return,
+        }
+    }
+}
+
+async fn write(name: &str, json: &str) {
+    match idb::put(&key(name), json).await {
+        Ok(()) => remove(name),
+        // (the json is already written, so straight into localStorage with it)
+        Err(_) => {
+            let saved = seed::window()
+                .local_storage()
+                .ok()
+                .flatten()
+                .is_some_and(|storage| storage.set_item(&key(name), json).is_ok());
+            if !saved {
+                seed::log!("couldn't save", name);
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+// one game shown in two windows on the same computer, like the teacher's screen and the
+// whiteboard. the tab that shares the game is in charge, the others send it their clicks and
+// show whatever state it sends back
+use crate::saved_game::SavedGame;
+use seed::prelude::*;
+use serde::{Deserialize, Serialize};
+use web_sys::{BroadcastChannel, MessageEvent};
+
+const CHANNEL: &str = "matching-seed:shared-game";
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Role {
+    // plays the game and sends every change out
+    Controller,
+    // only mirrors the controller
+    Follower,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum Message {
+    // a follower just joined and needs the board
+    Hello,
+    State(SavedGame),
+    // the controller left the game
+    Ended,
+    // clicks on a follower, for the controller to play
+    Guess(usize),
+    ShowHideAll,
+    PlayAgain,
+}
+
+pub struct Link {
+    pub role: Role,
+    channel: BroadcastChannel,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl Link {
+    pub fn open(role: Role, on_message: impl Fn(Message) + 'static) -> Result<Self, String> {
+        let channel = BroadcastChannel::new(CHANNEL)
+            .map_err(|error| format!("this browser can't share a game: {:?}", error))?;
+
+        let on_message = Closure::wrap(Box::new(move |event: MessageEvent| {
+            let message = event
+                .data()
+                .as_string()
+                .and_then(|json| serde_json::from_str(&json).ok());
+            if let Some(message) = message {
+                on_message(message);
+            }
+        }) as Box<dyn FnMut(MessageEvent)>);
+        channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            role,
+            channel,
+            _on_message: on_message,
+        })
+    }
+
+    pub fn send(&self, message: &Message) {
+        let json = serde_json::to_string(message).expect("serialize shared game message");
+        if let Err(error) = self.channel.post_message(&JsValue::from_str(&json)) {
+            seed::log!("couldn't send to the other window", error);
+        }
+    }
+
+    pub fn is_controller(&self) -> bool {
+        self.role == Role::Controller
+    }
+
+    pub fn is_follower(&self) -> bool {
+        self.role == Role::Follower
+    }
+}
+
+impl Drop for Link {
+    fn drop(&mut self) {
+        self.channel.set_onmessage(None);
+        self.channel.close();
+    }
+}
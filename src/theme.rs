@@ -0,0 +1,88 @@
+// colors and spacing as css custom properties, so a page embedding the game can restyle it
+// with plain css, like:
+//
+//     #app .matching-seed { --ms-card-background: #fffbe6; --ms-card-radius: 0; }
+use seed::{prelude::*, *};
+
+pub const ROOT_CLASS: &str = "matching-seed";
+// on a picture that's still loading
+pub const SKELETON_CLASS: &str = "ms-skeleton";
+// same, when there's a tiny preview of it to blur up
+pub const PREVIEW_CLASS: &str = "ms-blur-preview";
+
+// fallback look for when the host page doesn't load bulma
+const BASE_CSS: &str = include_str!("base.css");
+
+pub struct Theme {
+    pub accent: String,
+    pub card_background: String,
+    pub card_text: String,
+    pub card_radius: String,
+    pub board_gap: String,
+    pub drop_zone: String,
+    pub drop_zone_active: String,
+    pub drop_zone_border: String,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            accent: "#3273dc".to_string(),
+            card_background: "#ffffff".to_string(),
+            card_text: "#363636".to_string(),
+            card_radius: "0.25rem".to_string(),
+            board_gap: "0.75rem".to_string(),
+            drop_zone: "lightgray".to_string(),
+            drop_zone_active: "lightgreen".to_string(),
+            drop_zone_border: "black".to_string(),
+        }
+    }
+}
+
+impl Theme {
+    fn variables(&self) -> [(&'static str, &str); 8] {
+        [
+            ("--ms-accent", &self.accent),
+            ("--ms-card-background", &self.card_background),
+            ("--ms-card-text", &self.card_text),
+            ("--ms-card-radius", &self.card_radius),
+            ("--ms-board-gap", &self.board_gap),
+            ("--ms-drop-zone", &self.drop_zone),
+            ("--ms-drop-zone-active", &self.drop_zone_active),
+            ("--ms-drop-zone-border", &self.drop_zone_border),
+        ]
+    }
+}
+
+// the base styles, then the variables (and the few rules that use them) as a <style> element
+//
+// they're set on the root class instead of inline styles so any more specific selector in the
+// host page's css wins
+pub fn style_tag<Ms>(theme: &Theme) -> Node<Ms> {
+    let variables: String = theme
+        .variables()
+        .iter()
+        .map(|(name, value)| format!("{}: {};", name, value))
+        .collect();
+
+    custom![
+        Tag::Style,
+        format!(
+            "@layer matching-seed-base {{ {base} }} \
+             .{root} {{ {variables} }} \
+             .{root} .card {{ background: var(--ms-card-background); border-radius: var(--ms-card-radius); overflow: hidden; }} \
+             .{root} .card .title {{ color: var(--ms-card-text); }} \
+             .{root} .columns {{ margin-bottom: var(--ms-board-gap); }} \
+             .{root} .column {{ padding: calc(var(--ms-board-gap) / 2); }} \
+             .{root} a, .{root} .tabs li.is-active a {{ color: var(--ms-accent); border-bottom-color: var(--ms-accent); }} \
+             .{root} .{skeleton} {{ min-width: 8rem; min-height: 8rem; background: linear-gradient(90deg, #eeeeee 25%, #f7f7f7 50%, #eeeeee 75%); background-size: 200% 100%; animation: ms-shimmer 1.2s linear infinite; }} \
+             .{root} .{preview} {{ min-width: 8rem; min-height: 8rem; background-size: cover; background-position: center; filter: blur(8px); }} \
+             @keyframes ms-shimmer {{ from {{ background-position: 100% 0; }} to {{ background-position: -100% 0; }} }}",
+            base = BASE_CSS,
+            root = ROOT_CLASS,
+            skeleton = SKELETON_CLASS,
+            preview = PREVIEW_CLASS,
+            variables = variables,
+        )
+    ]
+}
@@ -0,0 +1,67 @@
+// turn image bytes into a small base64 data url that can live in a card
+use crate::image_worker;
+use image::{DynamicImage, ImageError, ImageFormat};
+use seed::{prelude::*, wasm_bindgen_futures};
+
+pub const THUMB_SIZE: u32 = 250;
+// the blurry stand-in shown while the real thumbnail loads, small enough to show right away
+const PREVIEW_SIZE: u32 = 16;
+
+pub fn data_url(bytes: &[u8]) -> Result<String, ImageError> {
+    let pic: DynamicImage = image::load_from_memory(bytes)?;
+
+    // from the bytes, a file's name can say anything
+    let format: ImageFormat = match image::guess_format(bytes)? {
+        format @ (ImageFormat::Gif | ImageFormat::Png | ImageFormat::Jpeg) => format,
+        // webp and the rest can be read but not written, so their thumbnails are pngs
+        _ => ImageFormat::Png,
+    };
+
+    let pic = pic.resize(THUMB_SIZE, THUMB_SIZE, image::imageops::Gaussian);
+
+    // from https://stackoverflow.com/questions/57457818/how-to-convert-dynamicimage-to-base64
+    let mut blob_buf = vec![];
+    pic.write_to(&mut blob_buf, format)?;
+    let resized_pic_b64: String = base64::encode(&blob_buf);
+
+    // make a nice url here
+    let format_string = match format {
+        ImageFormat::Gif => "image/gif",
+        ImageFormat::Png => "image/png",
+        _ => "image/jpeg",
+    };
+
+    Ok(format!("data:{};base64,{}", format_string, resized_pic_b64))
+}
+
+// read a picked file into a thumbnail
+pub async fn from_file(file: web_sys::File) -> Result<String, String> {
+    let buffer = wasm_bindgen_futures::JsFuture::from(file.array_buffer())
+        .await
+        .map_err(|error| format!("couldn't read the picture: {:?}", error))?;
+    let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+    image_worker::data_url(bytes)
+        .await
+        .map_err(|error| format!("that isn't a picture the game can use: {}", error))
+}
+
+// what the thumbnail worker (worker.js) calls, see image_worker.rs
+#[wasm_bindgen]
+pub fn worker_thumbnail(bytes: &[u8]) -> Result<String, JsValue> {
+    data_url(bytes).map_err(|error| JsValue::from_str(&error.to_string()))
+}
+
+// a tiny png of a thumbnail's data url, stretched and blurred while the full picture loads
+pub fn preview_url(photo: &str) -> Option<String> {
+    let (_, b64) = photo.split_once(";base64,")?;
+    let bytes = base64::decode(b64).ok()?;
+    let pic = image::load_from_memory(&bytes).ok()?;
+    let pic = pic.thumbnail(PREVIEW_SIZE, PREVIEW_SIZE);
+
+    let mut blob_buf = vec![];
+    pic.write_to(&mut blob_buf, ImageFormat::Png).ok()?;
+    Some(format!(
+        "data:image/png;base64,{}",
+        base64::encode(&blob_buf)
+    ))
+}
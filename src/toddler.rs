@@ -0,0 +1,49 @@
+// a locked down way to play for the littlest ones: big cards, nothing on the board that can
+// lose the deck, and the way out has to be held down for a couple of seconds
+use crate::Msg;
+use seed::{prelude::*, *};
+
+// fewer columns, so every card is bigger
+pub const COLUMNS: usize = 3;
+// how long the exit button has to be held
+pub const HOLD_MS: u32 = 2000;
+
+// a small button in the corner that only leaves the game once it's held long enough. the bar
+// fills up while it's held so a grown up can see it working
+pub fn exit_button(holding: bool) -> Node<Msg> {
+    div![
+        style![
+            St::Position => "fixed",
+            St::Bottom => rem(1),
+            St::Right => rem(1),
+            St::Width => rem(9),
+            St::Padding => rem(0.5),
+            St::Background => "rgba(255, 255, 255, 0.8)",
+            St::BorderRadius => px(6),
+            St::TextAlign => "center",
+            St::UserSelect => "none",
+            St::Cursor => "pointer",
+            // so a held finger doesn't bring up the browser's menu instead
+            "touch-action" => "none",
+            "-webkit-touch-callout" => "none",
+        ],
+        small!["hold to exit"],
+        div![style![
+            St::Height => px(4),
+            St::Background => "#3273dc",
+            St::Width => if holding { percent(100) } else { percent(0) },
+            St::Transition => if holding {
+                format!("width {}ms linear", HOLD_MS)
+            } else {
+                "none".to_string()
+            },
+        ],],
+        ev(Ev::MouseDown, |_| Msg::HoldExit),
+        ev(Ev::TouchStart, |_| Msg::HoldExit),
+        ev(Ev::MouseUp, |_| Msg::ReleaseExit),
+        ev(Ev::MouseLeave, |_| Msg::ReleaseExit),
+        ev(Ev::TouchEnd, |_| Msg::ReleaseExit),
+        ev(Ev::TouchCancel, |_| Msg::ReleaseExit),
+        ev(Ev::ContextMenu, |event| event.prevent_default()),
+    ]
+}
@@ -0,0 +1,163 @@
+// an opt in record of everything that happens in a game, with timestamps, for education
+// researchers (and for tuning how well a computer player should remember). it can be saved as
+// json or csv once the game is over
+use crate::{download, game::Outcome, Msg, PlayedCard};
+use seed::{prelude::*, *};
+use serde::Serialize;
+
+// a gap this long before a click counts as the player hesitating
+const HESITATION_MS: f64 = 3000.0;
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Kind {
+    Flip,
+    Match,
+    Miss,
+    // the player turned a wrong guess back over
+    Reset,
+    // a lone tile went back over by itself
+    FlipBack,
+    // a long pause before the next click
+    Hesitation,
+}
+
+impl Kind {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Flip => "flip",
+            Self::Match => "match",
+            Self::Miss => "miss",
+            Self::Reset => "reset",
+            Self::FlipBack => "flip_back",
+            Self::Hesitation => "hesitation",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Event {
+    // since the board was dealt
+    ms: f64,
+    kind: Kind,
+    // the board position, for the events that have one
+    tile: Option<usize>,
+    card: Option<String>,
+    // how long a hesitation lasted
+    pause_ms: Option<f64>,
+}
+
+#[derive(Serialize)]
+pub struct Trace {
+    #[serde(skip)]
+    started_at: f64,
+    // js time the game started, for lining up traces from different games
+    started: f64,
+    events: Vec<Event>,
+}
+
+impl Trace {
+    pub fn start() -> Self {
+        let now = js_sys::Date::now();
+        Self {
+            started_at: now,
+            started: now,
+            events: vec![],
+        }
+    }
+
+    fn push(&mut self, kind: Kind, tile: Option<(usize, &PlayedCard)>) {
+        let ms = js_sys::Date::now() - self.started_at;
+        // the time since the last thing that happened, or since the deal
+        let last = self.events.last().map_or(0.0, |event| event.ms);
+        if ms - last >= HESITATION_MS {
+            self.events.push(Event {
+                ms: last,
+                kind: Kind::Hesitation,
+                tile: None,
+                card: None,
+                pause_ms: Some((ms - last).round()),
+            });
+        }
+        self.events.push(Event {
+            ms,
+            kind,
+            tile: tile.map(|(index, _)| index),
+            card: tile.and_then(|(_, tile)| tile.card.text.clone()),
+            pause_ms: None,
+        });
+    }
+
+    // a click on the board, and what came of it
+    pub fn guess(&mut self, outcome: &Outcome, index: usize, board: &[PlayedCard]) {
+        let kind = match outcome {
+            Outcome::Ignored => return,
+            Outcome::Reset => return self.push(Kind::Reset, None),
+            Outcome::Flipped => Kind::Flip,
+            Outcome::Matched => Kind::Match,
+            Outcome::Missed => Kind::Miss,
+        };
+        self.push(kind, board.get(index).map(|tile| (index, tile)));
+    }
+
+    pub fn reset(&mut self) {
+        self.push(Kind::Reset, None);
+    }
+
+    pub fn flip_back(&mut self) {
+        self.push(Kind::FlipBack, None);
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("serialize trace")
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = "ms,event,tile,card,pause_ms\n".to_string();
+        for event in &self.events {
+            csv.push_str(&format!(
+                "{:.0},{},{},\"{}\",{}\n",
+                event.ms,
+                event.kind.name(),
+                event.tile.map(|tile| tile.to_string()).unwrap_or_default(),
+                event.card.as_deref().unwrap_or("").replace('"', "\"\""),
+                event
+                    .pause_ms
+                    .map(|pause| pause.to_string())
+                    .unwrap_or_default(),
+            ));
+        }
+        csv
+    }
+
+    pub fn save_json(&self) {
+        download::save_file("matching-trace.json", "application/json", &self.to_json());
+    }
+
+    pub fn save_csv(&self) {
+        download::save_file("matching-trace.csv", "text/csv", &self.to_csv());
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+pub fn export_buttons(trace: &Trace) -> Node<Msg> {
+    div![
+        C!["buttons"],
+        span![
+            C!["tag is-medium"],
+            format!("trace: {} events", trace.events.len())
+        ],
+        button![
+            "Save Trace (JSON)",
+            C!["button"],
+            ev(Ev::Click, |_| Msg::SaveTraceJson),
+        ],
+        button![
+            "Save Trace (CSV)",
+            C!["button"],
+            ev(Ev::Click, |_| Msg::SaveTraceCsv),
+        ],
+    ]
+}
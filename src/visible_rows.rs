@@ -0,0 +1,65 @@
+// keep track of which editor rows are on (or near) the screen, so a deck with a hundred photos
+// only puts the pictures in view on the page
+use seed::prelude::*;
+use ulid::Ulid;
+use web_sys::{Element, IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+// rows carry their card id in this attribute
+pub const ROW_ATTRIBUTE: &str = "data-row";
+
+// start showing a row's pictures a little before it scrolls in
+const MARGIN: &str = "300px";
+
+pub struct Observer {
+    observer: IntersectionObserver,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+}
+
+impl Observer {
+    // `on_change` gets the rows that came into or went out of view
+    pub fn new(on_change: impl Fn(Vec<(Ulid, bool)>) + 'static) -> Option<Self> {
+        let callback = Closure::wrap(Box::new(move |entries: js_sys::Array| {
+            let changes = entries
+                .iter()
+                .filter_map(|entry| {
+                    let entry = entry.unchecked_into::<IntersectionObserverEntry>();
+                    let id = entry.target().get_attribute(ROW_ATTRIBUTE)?;
+                    Some((Ulid::from_string(&id).ok()?, entry.is_intersecting()))
+                })
+                .collect();
+            on_change(changes);
+        }) as Box<dyn FnMut(js_sys::Array)>);
+
+        let options = IntersectionObserverInit::new();
+        options.set_root_margin(MARGIN);
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &options)
+                .ok()?;
+
+        Some(Self {
+            observer,
+            _callback: callback,
+        })
+    }
+
+    // watch every row on the page, rows that are already watched are skipped by the browser
+    pub fn observe_rows(&self) {
+        let rows = seed::document().query_selector_all(&format!("[{}]", ROW_ATTRIBUTE));
+        if let Ok(rows) = rows {
+            for index in 0..rows.length() {
+                if let Some(row) = rows
+                    .item(index)
+                    .and_then(|row| row.dyn_into::<Element>().ok())
+                {
+                    self.observer.observe(&row);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Observer {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
@@ -0,0 +1,122 @@
+// send a summary of every finished game to a user configured url, so results end up in the
+// teacher's spreadsheet or automation without copying them out by hand
+//
+// the url gets a POST with a json body like
+// {"deck": "animals", "player": "team 2", "won": true, "moves": 14, "seconds": 73.5, ...}
+use crate::{speedrun::Speedrun, Msg, PlayedCard};
+use seed::{prelude::*, *};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use ulid::Ulid;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WebhookSettings {
+    pub url: String,
+    // who's playing, sent along with the results
+    pub player: String,
+}
+
+impl WebhookSettings {
+    pub fn is_configured(&self) -> bool {
+        !self.url.trim().is_empty()
+    }
+}
+
+#[derive(Serialize)]
+pub struct Summary {
+    deck: String,
+    player: String,
+    won: bool,
+    moves: u32,
+    seconds: f64,
+    pairs: usize,
+    pairs_found: usize,
+    missed_pairs: Vec<MissedPair>,
+    // only with the speedrun timer on
+    time_ms: Option<f64>,
+    splits: Vec<Split>,
+}
+
+#[derive(Serialize)]
+struct Split {
+    text: String,
+    ms: f64,
+}
+
+#[derive(Serialize)]
+struct MissedPair {
+    text: String,
+    misses: u32,
+}
+
+impl Summary {
+    pub fn new(
+        settings: &WebhookSettings,
+        deck: &str,
+        board: &[PlayedCard],
+        misses: &BTreeMap<Ulid, u32>,
+        tile_flips: &[u32],
+        seconds: f64,
+        speedrun: Option<&Speedrun>,
+    ) -> Self {
+        let text = |id: &Ulid| {
+            board
+                .iter()
+                .find(|tile| tile.card.id == *id)
+                .and_then(|tile| tile.card.text.clone())
+                .unwrap_or_default()
+        };
+        let mut missed_pairs: Vec<MissedPair> = misses
+            .iter()
+            .map(|(id, misses)| MissedPair {
+                text: text(id),
+                misses: *misses,
+            })
+            .collect();
+        missed_pairs.sort_by_key(|pair| std::cmp::Reverse(pair.misses));
+
+        let pairs_found = board.iter().filter(|tile| tile.matched).count() / 2;
+        let pairs = board.len() / 2;
+        Self {
+            deck: if deck.is_empty() { "untitled" } else { deck }.to_string(),
+            player: settings.player.trim().to_string(),
+            won: pairs > 0 && pairs_found == pairs,
+            moves: crate::game::moves(tile_flips),
+            seconds: (seconds * 10.0).round() / 10.0,
+            pairs,
+            pairs_found,
+            missed_pairs,
+            time_ms: speedrun.map(|speedrun| speedrun.elapsed().round()),
+            splits: speedrun
+                .map(Speedrun::splits)
+                .unwrap_or_default()
+                .iter()
+                .map(|(id, ms)| Split {
+                    text: text(id),
+                    ms: ms.round(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub async fn send(settings: WebhookSettings, summary: Summary) -> Result<(), String> {
+    Request::new(settings.url.trim().to_string())
+        .method(Method::Post)
+        .json(&summary)
+        .map_err(|error| format!("couldn't make request: {:?}", error))?
+        .fetch()
+        .await
+        .and_then(Response::check_status)
+        .map_err(|error| format!("sending results failed: {:?}", error))?;
+    Ok(())
+}
+
+// how the last send went, under the board
+pub fn status(result: &Result<(), String>) -> Node<Msg> {
+    match result {
+        Ok(()) => p![C!["help is-success"], "results sent"],
+        Err(error) => p![C!["help is-danger"], error],
+    }
+}
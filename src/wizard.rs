@@ -0,0 +1,95 @@
+// "what kind of deck?" templates that set things up for teachers who don't want to dig
+// through settings and generators
+use crate::{game::Mode, generators, generators::Kind, Model, Msg};
+use seed::{prelude::*, *};
+
+#[derive(Clone, Copy)]
+pub enum Template {
+    Vocabulary,
+    Translations,
+    Letters,
+    Numbers,
+    Custom,
+}
+
+impl Template {
+    const ALL: [Self; 5] = [
+        Self::Vocabulary,
+        Self::Translations,
+        Self::Letters,
+        Self::Numbers,
+        Self::Custom,
+    ];
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Vocabulary => "Vocabulary",
+            Self::Translations => "Words & translations",
+            Self::Letters => "Letters",
+            Self::Numbers => "Numbers",
+            Self::Custom => "Custom",
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Vocabulary => "pictures with the word underneath, drop or search for pictures",
+            Self::Translations => "a word on one card and its translation on the other",
+            Self::Letters => "match big letters with small letters",
+            Self::Numbers => "match math problems with their answers",
+            Self::Custom => "start with an empty deck",
+        }
+    }
+}
+
+// set the app up for a template: how it's played, what a pair is, and the generator that makes
+// its cards
+pub fn apply(template: Template, model: &mut Model) {
+    model.wizard_dismissed = true;
+    let (mode, picture_word_pairs, generator) = match template {
+        // a picture on one tile and its word on the other
+        Template::Vocabulary => (Mode::Classic, true, None),
+        // word and translation are two texts on the same card, each gets a tile
+        Template::Translations => (Mode::Classic, false, None),
+        // the whole alphabet is a big board, rounds deal it a few letters at a time
+        Template::Letters => (Mode::Rounds, false, Some(Kind::Letters)),
+        Template::Numbers => (Mode::Classic, false, Some(Kind::Math)),
+        Template::Custom => (Mode::Classic, false, None),
+    };
+    model.mode = mode;
+    model.picture_word_pairs = picture_word_pairs;
+    generators::start(&mut model.generators, generator);
+    // translations can be looked up once a dictionary is picked in the settings
+    if matches!(template, Template::Translations) {
+        model.settings_open = true;
+    }
+}
+
+pub fn view() -> Node<Msg> {
+    div![
+        C!["box"],
+        h2![C!["title is-4"], "What kind of deck?"],
+        div![
+            C!["columns is-multiline"],
+            Template::ALL.iter().map(|template| {
+                let template = *template;
+                div![
+                    C!["column is-one-fifth"],
+                    button![
+                        C!["button is-fullwidth is-link is-light"],
+                        style![St::Height => "auto", St::WhiteSpace => "normal", St::FlexDirection => "column"],
+                        strong![template.name()],
+                        span![C!["is-size-7"], template.description()],
+                        ev(Ev::Click, move |_| Msg::ChooseTemplate(template)),
+                    ]
+                ]
+            }),
+        ],
+        button![
+            "🎲 Surprise me!",
+            C!["button is-large is-warning"],
+            attrs! {At::Title => "play right away with a random emoji deck"},
+            ev(Ev::Click, |_| Msg::SurpriseMe),
+        ],
+    ]
+}
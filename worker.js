@@ -0,0 +1,13 @@
+// makes card thumbnails off the page's thread, with the game's own wasm (see src/image_worker.rs)
+import init, { worker_thumbnail } from '/matching-seed/pkg/package.js';
+
+const ready = init('/matching-seed/pkg/package_bg.wasm');
+
+onmessage = async ({ data: { id, bytes } }) => {
+    await ready;
+    try {
+        postMessage({ id, url: worker_thumbnail(bytes) });
+    } catch (error) {
+        postMessage({ id, error: String(error) });
+    }
+};